@@ -1,5 +1,7 @@
 #!/usr/bin/env bash
 # echo on
 cargo fmt
-cargo clippy --all-targets --all-features -- -D warnings
-cargo test --workspace
+cargo clippy --all-targets --features "rayon constant-time derive regex glob rules serde" -- -D warnings
+cargo test --workspace --features "rayon constant-time derive regex glob rules serde"
+# the simd feature requires a nightly compiler
+cargo +nightly test --workspace --all-features -- --skip macro_expansion
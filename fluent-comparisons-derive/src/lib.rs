@@ -0,0 +1,202 @@
+//! This crate contains the derive macros for the fluent-comparisons crate.
+//!
+//! Use the [Quantify] derive macro through the `derive` feature of the `fluent-comparisons` crate
+//! rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index, LitStr, Member};
+
+/// Derive methods that quantify over the fields of a struct.
+///
+/// All fields of the struct that take part in the quantification must be of the same type `T`. Other fields
+/// are excluded with the attribute `#[quantify(skip)]`. Fields can be assigned to one or more groups with
+/// `#[quantify(group = "name")]`. The derive generates the following methods for the struct, which all visit
+/// the fields in the order of declaration:
+///
+/// * `fields(&self) -> [&T; N]` gives references to the fields.
+/// * `fields_in_group(&self, group: &str) -> impl Iterator<Item = &T>` gives references to the fields of a group.
+/// * `all_fields(&self, predicate)`, `any_fields(&self, predicate)` and `none_fields(&self, predicate)` decide whether the predicate
+///   `FnMut(&T) -> bool` holds for all, any or none of the fields. The predicate is evaluated lazily.
+///
+/// Furthermore, it implements `IntoIterator` for references to the struct, so that the macros can spread the fields
+/// into a set like `all_of!({..&s} < 90)`. New fields are then picked up automatically.
+///
+/// ```
+/// # use fluent_comparisons_derive::Quantify;
+/// #[derive(Quantify)]
+/// struct Usage {
+///     #[quantify(group = "load")]
+///     cpu: u32,
+///     #[quantify(group = "load")]
+///     mem: u32,
+///     disk: u32,
+///     #[quantify(skip)]
+///     host: &'static str,
+/// }
+///
+/// let usage = Usage { cpu: 40, mem: 95, disk: 10, host: "db" };
+/// assert!(usage.any_fields(|v| *v > 90));
+/// assert!(!usage.all_fields(|v| *v < 90));
+/// assert_eq!(usage.fields_in_group("load").copied().max(), Some(95));
+/// assert_eq!(usage.fields(), [&40, &95, &10]);
+/// ```
+#[proc_macro_derive(Quantify, attributes(quantify))]
+pub fn derive_quantify(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_quantify(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// A field of the struct that takes part in the quantification.
+struct QuantifiedField {
+    member: Member,
+    ty: syn::Type,
+    groups: Vec<LitStr>,
+    span: Span,
+}
+
+fn expand_quantify(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "Quantify can only be derived for structs",
+            ))
+        }
+    };
+    let fields = quantified_fields(fields)?;
+    let first = fields.first().ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "Quantify requires at least one field that is not skipped",
+        )
+    })?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let value_type = &first.ty;
+    let count = fields.len();
+    let references: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            let member = &field.member;
+            quote_spanned!(field.span=> &self.#member)
+        })
+        .collect();
+    let group_selections = fields.iter().map(|field| {
+        let member = &field.member;
+        let groups = &field.groups;
+        if groups.is_empty() {
+            quote!(::core::option::Option::None)
+        } else {
+            quote!(if ::core::matches!(group, #(#groups)|*) {
+                ::core::option::Option::Some(&self.#member)
+            } else {
+                ::core::option::Option::None
+            })
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut ref_generics = input.generics.clone();
+    ref_generics
+        .params
+        .insert(0, syn::parse_quote!('__quantify));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// References to the quantified fields in the order of declaration.
+            #[inline]
+            #vis fn fields(&self) -> [&#value_type; #count] {
+                [#(#references),*]
+            }
+
+            /// References to the quantified fields of the given group in the order of declaration.
+            #[inline]
+            #vis fn fields_in_group(&self, group: &str) -> impl ::core::iter::Iterator<Item = &#value_type> {
+                let selected = [#(#group_selections),*];
+                ::core::iter::IntoIterator::into_iter(selected).flatten()
+            }
+
+            /// Decide whether the predicate holds for all quantified fields, evaluating it lazily in the order of declaration.
+            #[inline]
+            #vis fn all_fields(&self, predicate: impl ::core::ops::FnMut(&#value_type) -> bool) -> bool {
+                ::core::iter::IntoIterator::into_iter(self.fields()).all(predicate)
+            }
+
+            /// Decide whether the predicate holds for any of the quantified fields, evaluating it lazily in the order of declaration.
+            #[inline]
+            #vis fn any_fields(&self, predicate: impl ::core::ops::FnMut(&#value_type) -> bool) -> bool {
+                ::core::iter::IntoIterator::into_iter(self.fields()).any(predicate)
+            }
+
+            /// Decide whether the predicate holds for none of the quantified fields, evaluating it lazily in the order of declaration.
+            #[inline]
+            #vis fn none_fields(&self, predicate: impl ::core::ops::FnMut(&#value_type) -> bool) -> bool {
+                !::core::iter::IntoIterator::into_iter(self.fields()).any(predicate)
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::IntoIterator for &'__quantify #name #ty_generics #where_clause {
+            type Item = &'__quantify #value_type;
+            type IntoIter = ::core::array::IntoIter<&'__quantify #value_type, #count>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.fields())
+            }
+        }
+    })
+}
+
+/// Collect the fields that are not skipped together with their groups.
+fn quantified_fields(fields: &Fields) -> syn::Result<Vec<QuantifiedField>> {
+    let mut quantified = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let mut skip = false;
+        let mut groups = Vec::new();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("quantify"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("group") {
+                    groups.push(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip` or `group = \"...\"`"))
+                }
+            })?;
+        }
+        if skip && !groups.is_empty() {
+            return Err(syn::Error::new(
+                field.span(),
+                "a skipped field can not be part of a group",
+            ));
+        }
+        if !skip {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+            quantified.push(QuantifiedField {
+                member,
+                ty: field.ty.clone(),
+                groups,
+                span: field.span(),
+            });
+        }
+    }
+    Ok(quantified)
+}
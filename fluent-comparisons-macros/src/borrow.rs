@@ -0,0 +1,17 @@
+//! Support functions for the borrowing transformations of the macros.
+//!
+//! The `.map_ref(...)` and `.satisfy_ref(...)` forms of the macros pass a reference to each value of the set
+//! to the transformation instead of moving the value, so that the values stay usable after the macro.
+//! Since the values can be temporaries, the references have different lifetimes. A closure whose
+//! argument type is inferred only accepts references of a single lifetime, though. That is why
+//! the macros pass closures through [higher_ranked] first.
+
+/// Make a closure accept references of any lifetime to values of the same type as `first`.
+///
+/// This fixes the signature of the closure to `for<'a> Fn(&'a T) -> R`, which also lets the compiler infer the
+/// type of the closure argument from `first`. The returned value of the closure can not borrow from the argument,
+/// though. For that, use a function instead of a closure.
+#[inline]
+pub fn higher_ranked<T: ?Sized, R, F: Fn(&T) -> R>(_first: &T, map_func: F) -> F {
+    map_func
+}
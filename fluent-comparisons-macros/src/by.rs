@@ -0,0 +1,31 @@
+//! Support functions for comparisons using a custom ordering.
+//!
+//! The `.by(cmp)` modifier of the macros compares the values to the right hand side using a comparator
+//! `cmp(&value, &rhs) -> Ordering`, like [slice::sort_by], and interprets the comparison operator through the returned
+//! [Ordering]. The `.by_key(key)` modifier compares `key(&value)` with `key(&rhs)`, like [slice::sort_by_key].
+//! This module contains comparators that are useful with `.by(...)`, e.g. [ignore_ascii_case].
+
+use core::cmp::Ordering;
+
+/// Make a comparator closure accept references of any lifetime to values of the same type as `first`.
+///
+/// This is the counterpart of [higher_ranked](crate::borrow::higher_ranked) for the closures given to `.by(...)`,
+/// which also lets the compiler infer the argument types of the closure from `first`.
+#[inline]
+pub fn comparator<T: ?Sized, F: Fn(&T, &T) -> Ordering>(_first: &T, cmp_func: F) -> F {
+    cmp_func
+}
+
+/// Compare two strings lexicographically, ignoring the case of ASCII letters.
+///
+/// This does not allocate, in contrast to comparing `str::to_lowercase` keys. Use it with `.by(...)`:
+/// ```
+/// # use fluent_comparisons_macros::{any_of,by::ignore_ascii_case};
+/// let (a, b) = ("Admin", "guest");
+/// assert!(any_of!({a,b}.by(ignore_ascii_case) == "ADMIN"));
+/// ```
+pub fn ignore_ascii_case(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.bytes().map(|byte| byte.to_ascii_lowercase());
+    let rhs = rhs.bytes().map(|byte| byte.to_ascii_lowercase());
+    lhs.cmp(rhs)
+}
@@ -0,0 +1,67 @@
+//! Support types for the concurrent asynchronous macros.
+//!
+//! The macros `any_of_concurrent`, `all_of_concurrent` and `none_of_concurrent` turn each value of
+//! the set into a future that resolves to the result of the comparison for that value and then race
+//! these futures against each other using [race]. This only depends on `core::future`, so it works
+//! with any executor.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A future that polls a set of futures concurrently until one of them resolves to a decisive value.
+///
+/// It resolves to `true` as soon as one of the futures resolves to the decisive value and to `false`
+/// if all futures resolved to the other value. Futures that have resolved are not polled again and
+/// once the result is known, none of the remaining futures are polled anymore.
+/// Use [race] to create an instance.
+pub struct Race<'a, const N: usize> {
+    futures: [Option<Pin<&'a mut dyn Future<Output = bool>>>; N],
+    decisive: bool,
+}
+
+/// Race the given futures concurrently until one of them resolves to the `decisive` value.
+///
+/// ```
+/// # use fluent_comparisons_macros::concurrent::race;
+/// # use core::pin::pin;
+/// let race = race(true, [pin!(async { false }), pin!(async { true })]);
+/// ```
+#[inline]
+pub fn race<const N: usize>(
+    decisive: bool,
+    futures: [Pin<&mut dyn Future<Output = bool>>; N],
+) -> Race<'_, N> {
+    Race {
+        futures: futures.map(Some),
+        decisive,
+    }
+}
+
+impl<const N: usize> Future for Race<'_, N> {
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        // the futures are already pinned, so the race itself does not need to be
+        let this = self.get_mut();
+        let mut pending = false;
+        for slot in this.futures.iter_mut() {
+            if let Some(future) = slot {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(value) => {
+                        *slot = None;
+                        if value == this.decisive {
+                            return Poll::Ready(true);
+                        }
+                    }
+                    Poll::Pending => pending = true,
+                }
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(false)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Support for the constant time macros, which are available with the `constant-time` feature.
+//!
+//! The macros `ct_any_of`, `ct_all_of` and `ct_none_of` compare the values using the traits of the
+//! [subtle](https://docs.rs/subtle) crate, which is re-exported here, and combine the resulting
+//! [Choice](subtle::Choice)s without branching.
+
+pub use subtle;
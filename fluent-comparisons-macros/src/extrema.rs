@@ -0,0 +1,85 @@
+//! Support functions for the macros that find the minimum or maximum of a set of values.
+//!
+//! The macros [min_of](crate::min_of), [max_of](crate::max_of), [minmax_of](crate::minmax_of),
+//! [argmin_of](crate::argmin_of) and [argmax_of](crate::argmax_of) evaluate the values of the set into an array, so they must have the same
+//! type, and use the functions in this module to find the position of the extremum. The values only need to implement `PartialOrd`,
+//! so the functions define what happens for values that are not ordered:
+//!
+//! * A value that is unordered with respect to itself, like `NaN`, is skipped. So the extremum is `NaN` only if all values are `NaN`,
+//!   which is the same behavior as [f64::min] and [f64::max].
+//! * If two values are unordered with respect to each other, but each of them is ordered with respect to itself, the value that
+//!   comes first is kept.
+//! * Like for [Iterator::min] and [Iterator::max], the minimum is the first of several equal minimal values and
+//!   the maximum is the last of several equal maximal values.
+
+/// The index of the first minimal value of a non-empty slice.
+#[inline]
+pub fn argmin<T: PartialOrd>(values: &[T]) -> usize {
+    extremum(values.iter(), |value, min| value < min)
+}
+
+/// The index of the last maximal value of a non-empty slice.
+#[inline]
+pub fn argmax<T: PartialOrd>(values: &[T]) -> usize {
+    extremum(values.iter(), |value, max| value >= max)
+}
+
+/// The index of the first value with the minimal key in a non-empty slice. The key is computed once per value.
+#[inline]
+pub fn argmin_by_key<T, K: PartialOrd>(values: &[T], key: impl FnMut(&T) -> K) -> usize {
+    extremum(values.iter().map(key), |value, min| value < min)
+}
+
+/// The index of the last value with the maximal key in a non-empty slice. The key is computed once per value.
+#[inline]
+pub fn argmax_by_key<T, K: PartialOrd>(values: &[T], key: impl FnMut(&T) -> K) -> usize {
+    extremum(values.iter().map(key), |value, max| value >= max)
+}
+
+/// The indices of the first minimal and the last maximal value of a non-empty slice.
+#[inline]
+pub fn argminmax<T: PartialOrd>(values: &[T]) -> (usize, usize) {
+    (argmin(values), argmax(values))
+}
+
+/// The indices of the first value with the minimal key and the last value with the maximal key in a non-empty slice.
+/// The key is computed twice per value, once for the minimum and once for the maximum.
+#[inline]
+pub fn argminmax_by_key<T, K: PartialOrd>(
+    values: &[T],
+    mut key: impl FnMut(&T) -> K,
+) -> (usize, usize) {
+    (argmin_by_key(values, &mut key), argmax_by_key(values, key))
+}
+
+/// Move the value at the given index out of the array and drop the others.
+#[inline]
+pub fn take<T, const N: usize>(values: [T; N], index: usize) -> T {
+    IntoIterator::into_iter(values)
+        .nth(index)
+        .expect("the index of the extremum is within the array")
+}
+
+/// Move the values at the given indices of the minimum and the maximum out of the array. The minimum is cloned,
+/// because it is the same value as the maximum if there is only one ordered value.
+#[inline]
+pub fn take_pair<T: Clone, const N: usize>(values: [T; N], (min, max): (usize, usize)) -> (T, T) {
+    (values[min].clone(), take(values, max))
+}
+
+/// Find the index of the extremum of the keys, where `replaces(key, current)` decides whether a key replaces the current extremum.
+/// A current extremum that is unordered with respect to itself is always replaced.
+fn extremum<K: PartialOrd>(
+    mut keys: impl Iterator<Item = K>,
+    replaces: impl Fn(&K, &K) -> bool,
+) -> usize {
+    let mut current = keys.next().expect("the set of values is never empty");
+    let mut current_index = 0;
+    for (index, key) in keys.enumerate() {
+        if replaces(&key, &current) || current.partial_cmp(&current).is_none() {
+            current = key;
+            current_index = index + 1;
+        }
+    }
+    current_index
+}
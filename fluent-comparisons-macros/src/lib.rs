@@ -1,4 +1,142 @@
 //! This crate contains the macros for the fluent-comparisons crate
+#![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+#[cfg(feature = "rules")]
+extern crate alloc;
+
+pub mod borrow;
+pub mod by;
+pub mod concurrent;
+#[cfg(feature = "constant-time")]
+pub mod constant_time;
+pub mod extrema;
+pub mod lift;
+pub mod membership;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(any(feature = "regex", feature = "glob"))]
+pub mod pattern;
+pub mod pipeline;
+#[cfg(feature = "rules")]
+pub mod rules;
+pub mod simd;
+pub mod total;
+pub mod values;
+
+#[cfg(feature = "derive")]
+pub use fluent_comparisons_derive::Quantify;
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro quantifies over a set of values that is only known at runtime, i.e. the argument of the closure for the placeholder
+/// `{_}` or the spread values `{..values}`. It splits the rest of the invocation into the modifiers like `.map(...)` and the comparison,
+/// so that the right hand side is evaluated only once. The functions given to the modifiers are evaluated once as well, except for
+/// closures, which are passed on as they are, so that their argument types are inferred. Then the quantifier `$element` is applied
+/// to each single value and the results are combined using the [values](crate::values) module. For `none_of`, the element
+/// quantifier is `any_of` and the results are negated once for all values.
+macro_rules! __placeholder_set {
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . $method:ident () $($rest:tt)*) => {
+        $crate::__placeholder_set!($quantifier, $element, $values, [$($modifiers)* . $method ()], $($rest)*)
+    };
+    // the pattern is compiled once for all values
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . matches_regex($pattern:expr)) => {
+        $crate::__with_regex!($pattern, pattern => $crate::values::$quantifier($values, |value| {
+            $crate::$element!({value} $($modifiers)* .satisfy(|value| $crate::pattern::is_regex_match(&pattern, value)))
+        }))
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . matches_glob($pattern:expr)) => {
+        $crate::__with_glob!($pattern, pattern => $crate::values::$quantifier($values, |value| {
+            $crate::$element!({value} $($modifiers)* .satisfy(|value| $crate::pattern::is_glob_match(&pattern, value)))
+        }))
+    };
+    // modifiers whose arguments are values rather than functions
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . lift_or $args:tt $($rest:tt)*) => {
+        $crate::__placeholder_set!($quantifier, $element, $values, [$($modifiers)* . lift_or $args], $($rest)*)
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . $method:ident (| $($closure:tt)*) $($rest:tt)*) => {
+        $crate::__placeholder_set!($quantifier, $element, $values, [$($modifiers)* . $method (| $($closure)*)], $($rest)*)
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . $method:ident (move $($closure:tt)*) $($rest:tt)*) => {
+        $crate::__placeholder_set!($quantifier, $element, $values, [$($modifiers)* . $method (move $($closure)*)], $($rest)*)
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], . $method:ident ($func:expr) $($rest:tt)*) => {
+        {
+            let func = &$func;
+            $crate::__placeholder_set!($quantifier, $element, $values, [$($modifiers)* . $method (func)], $($rest)*)
+        }
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], ? $($rest:tt)*) => {
+        $crate::__placeholder_set!($quantifier, $element, $values, [$($modifiers)* ?], $($rest)*)
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], ) => {
+        $crate::values::$quantifier($values, |value| $crate::$element!({value} $($modifiers)*))
+    };
+    ($quantifier:ident, $element:ident, $values:ident, [$($modifiers:tt)*], $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let rhs = $rhs;
+            $crate::values::$quantifier($values, |value| $crate::$element!({value} $($modifiers)* $operator rhs))
+        }
+    };
+}
+
+#[cfg(feature = "regex")]
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro compiles the pattern of `.matches_regex(...)` once per invocation of the quantifier and evaluates the body
+/// with the compiled pattern bound to `$name`. If the pattern can't be compiled, the body is not evaluated and the macro
+/// evaluates to the error.
+macro_rules! __with_regex {
+    ($pattern:expr, $name:ident => $body:expr) => {{
+        let (compiled, into_output) = $crate::pattern::compile_regex($pattern);
+        into_output(match compiled {
+            ::core::result::Result::Ok($name) => ::core::result::Result::Ok($body),
+            ::core::result::Result::Err(error) => ::core::result::Result::Err(error),
+        })
+    }};
+}
+
+#[cfg(not(feature = "regex"))]
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro explains that `.matches_regex(...)` requires the `regex` feature.
+macro_rules! __with_regex {
+    ($pattern:expr, $name:ident => $body:expr) => {
+        ::core::compile_error!("matches_regex requires the regex feature of fluent-comparisons")
+    };
+}
+
+#[cfg(feature = "glob")]
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro compiles the pattern of `.matches_glob(...)` once per invocation of the quantifier and evaluates the body
+/// with the compiled pattern bound to `$name`. If the pattern can't be compiled, the body is not evaluated and the macro
+/// evaluates to the error.
+macro_rules! __with_glob {
+    ($pattern:expr, $name:ident => $body:expr) => {{
+        let (compiled, into_output) = $crate::pattern::compile_glob($pattern);
+        into_output(match compiled {
+            ::core::result::Result::Ok($name) => ::core::result::Result::Ok($body),
+            ::core::result::Result::Err(error) => ::core::result::Result::Err(error),
+        })
+    }};
+}
+
+#[cfg(not(feature = "glob"))]
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro explains that `.matches_glob(...)` requires the `glob` feature.
+macro_rules! __with_glob {
+    ($pattern:expr, $name:ident => $body:expr) => {
+        ::core::compile_error!("matches_glob requires the glob feature of fluent-comparisons")
+    };
+}
 
 #[macro_export]
 #[doc(hidden)]
@@ -24,6 +162,297 @@ macro_rules! __check_operator {
     };
 }
 
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro collects the tokens of the value of a membership test `value == {...}` one by one until only the operator
+/// and the set of literals are left, because a macro pattern can't match an arbitrary expression followed by `==`.
+/// The value is evaluated once and looked up in a [SortedStrs](crate::membership::SortedStrs) table for string literals
+/// or matched against the literals as patterns otherwise. The result is negated if the first argument is `[!]`.
+macro_rules! __membership {
+    // unwrap parenthesized values, so that the parentheses don't trigger the unused_parens lint
+    ([$($negation:tt)?] == [($value:expr)] == {$($literals:literal),+ $(,)?}) => {
+        $crate::__membership!(@lookup [$($negation)?] $value, $($literals),+)
+    };
+    ([$($negation:tt)?] != [($value:expr)] != {$($literals:literal),+ $(,)?}) => {
+        $crate::__membership!(@lookup [$($negation)?] $value, $($literals),+)
+    };
+    ([$($negation:tt)?] == [$($value:tt)+] == {$($literals:literal),+ $(,)?}) => {
+        $crate::__membership!(@lookup [$($negation)?] $($value)+, $($literals),+)
+    };
+    ([$($negation:tt)?] != [$($value:tt)+] != {$($literals:literal),+ $(,)?}) => {
+        $crate::__membership!(@lookup [$($negation)?] $($value)+, $($literals),+)
+    };
+    (@lookup [$($negation:tt)?] $value:expr, $($literals:literal),+) => {
+        $($negation)? match $value {
+            value => if $crate::membership::is_str(&value) {
+                $crate::membership::contains(
+                    &const { $crate::membership::SortedStrs::from_literals([$($literals),+]) },
+                    value,
+                )
+            } else {
+                // duplicate literals are harmless in a set
+                #[allow(unreachable_patterns)]
+                let matched = ::core::matches!(value, $($literals)|+);
+                matched
+            },
+        }
+    };
+    ([$($negation:tt)?] $operator:tt [$($value:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__membership!([$($negation)?] $operator [$($value)* $next] $($rest)*)
+    };
+    ([$($negation:tt)?] $operator:tt [$($value:tt)*]) => {
+        ::core::compile_error!(::core::concat!(
+            "Expected a set of values in braces like `{a, b}` or a membership test like `x ",
+            ::core::stringify!($operator),
+            " {1, 2}` against a set of literals"
+        ))
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro decides whether an [Ordering](core::cmp::Ordering) of a left hand side relative to a
+/// right hand side satisfies the given comparison operator.
+macro_rules! __ordering_satisfies {
+    ($ordering:expr, ==) => {
+        $ordering == ::core::cmp::Ordering::Equal
+    };
+    ($ordering:expr, !=) => {
+        $ordering != ::core::cmp::Ordering::Equal
+    };
+    ($ordering:expr, <=) => {
+        $ordering != ::core::cmp::Ordering::Greater
+    };
+    ($ordering:expr, >=) => {
+        $ordering != ::core::cmp::Ordering::Less
+    };
+    ($ordering:expr, <) => {
+        $ordering == ::core::cmp::Ordering::Less
+    };
+    ($ordering:expr, >) => {
+        $ordering == ::core::cmp::Ordering::Greater
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro compares a left hand side and a right hand side using [PartialOrd::partial_cmp]. It
+/// evaluates to `Some(result)` of the comparison with the given operator or to `None` if the two
+/// values are unordered with respect to each other.
+macro_rules! __strict_compare {
+    ($lhs:expr, $operator:tt, $rhs:expr) => {
+        ::core::cmp::PartialOrd::partial_cmp(&$lhs, &$rhs)
+            .map(|ordering| $crate::__ordering_satisfies!(ordering, $operator))
+    };
+}
+
+#[cfg(feature = "constant-time")]
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro compares a left hand side and a right hand side in constant time using the traits of
+/// the subtle crate and evaluates to the resulting `subtle::Choice`.
+macro_rules! __ct_compare {
+    ($lhs:expr, ==, $rhs:expr) => {{
+        use $crate::constant_time::subtle::ConstantTimeEq as _;
+        $lhs.ct_eq(&$rhs)
+    }};
+    ($lhs:expr, !=, $rhs:expr) => {{
+        use $crate::constant_time::subtle::ConstantTimeEq as _;
+        !$lhs.ct_eq(&$rhs)
+    }};
+    ($lhs:expr, >, $rhs:expr) => {{
+        use $crate::constant_time::subtle::ConstantTimeGreater as _;
+        $lhs.ct_gt(&$rhs)
+    }};
+    ($lhs:expr, <, $rhs:expr) => {{
+        use $crate::constant_time::subtle::ConstantTimeLess as _;
+        $lhs.ct_lt(&$rhs)
+    }};
+    ($lhs:expr, >=, $rhs:expr) => {{
+        use $crate::constant_time::subtle::ConstantTimeLess as _;
+        !$lhs.ct_lt(&$rhs)
+    }};
+    ($lhs:expr, <=, $rhs:expr) => {{
+        use $crate::constant_time::subtle::ConstantTimeGreater as _;
+        !$lhs.ct_gt(&$rhs)
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro applies the comparison with the given operator to a quantifier of the [simd](crate::simd) module.
+macro_rules! __simd_compare {
+    ($quantifier:expr, ==, $rhs:expr) => {
+        $quantifier.eq($rhs)
+    };
+    ($quantifier:expr, !=, $rhs:expr) => {
+        $quantifier.ne($rhs)
+    };
+    ($quantifier:expr, <, $rhs:expr) => {
+        $quantifier.lt($rhs)
+    };
+    ($quantifier:expr, <=, $rhs:expr) => {
+        $quantifier.le($rhs)
+    };
+    ($quantifier:expr, >, $rhs:expr) => {
+        $quantifier.gt($rhs)
+    };
+    ($quantifier:expr, >=, $rhs:expr) => {
+        $quantifier.ge($rhs)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro pairs the values of a set with their indices as literals, so that no runtime counter is needed,
+/// and passes the set of pairs on to the quantifier together with the remaining tokens of the invocation.
+/// The list of available indices limits the number of values that can be enumerated.
+macro_rules! __enumerate {
+    ($quantifier:ident, {$($lh_sides:expr),+} $($rest:tt)+) => {
+        $crate::__enumerate!($quantifier, [], [0usize 1usize 2usize 3usize 4usize 5usize 6usize 7usize 8usize 9usize 10usize 11usize 12usize 13usize 14usize 15usize 16usize 17usize 18usize 19usize 20usize 21usize 22usize 23usize 24usize 25usize 26usize 27usize 28usize 29usize 30usize 31usize 32usize 33usize 34usize 35usize 36usize 37usize 38usize 39usize 40usize 41usize 42usize 43usize 44usize 45usize 46usize 47usize 48usize 49usize 50usize 51usize 52usize 53usize 54usize 55usize 56usize 57usize 58usize 59usize 60usize 61usize 62usize 63usize], [$($lh_sides),+], $($rest)+)
+    };
+    ($quantifier:ident, [$($pairs:expr),*], [$index:literal $($indices:literal)*], [$lh_side:expr $(, $lh_sides:expr)*], $($rest:tt)+) => {
+        $crate::__enumerate!($quantifier, [$($pairs,)* ($index, $lh_side)], [$($indices)*], [$($lh_sides),*], $($rest)+)
+    };
+    ($quantifier:ident, [$($pairs:expr),+], $indices:tt, [], $($rest:tt)+) => {
+        $crate::$quantifier!({$($pairs),+} $($rest)+)
+    };
+    ($quantifier:ident, $pairs:tt, [], [$($lh_sides:expr),+], $($rest:tt)+) => {
+        ::core::compile_error!("`.enumerate()` supports at most 64 values")
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro pairs the values of two sets by their position and passes the set of pairs on to the quantifier
+/// together with the remaining tokens of the invocation. Sets of different sizes are rejected with a compile error.
+macro_rules! __zip {
+    ($quantifier:ident, [$($pairs:expr),*], [$lh_side:expr $(, $lh_sides:expr)*], [$other_side:expr $(, $other_sides:expr)*], $($rest:tt)+) => {
+        $crate::__zip!($quantifier, [$($pairs,)* ($lh_side, $other_side)], [$($lh_sides),*], [$($other_sides),*], $($rest)+)
+    };
+    ($quantifier:ident, [$($pairs:expr),+], [], [], $($rest:tt)+) => {
+        $crate::$quantifier!({$($pairs),+} $($rest)+)
+    };
+    ($quantifier:ident, $pairs:tt, $lh_sides:tt, $other_sides:tt, $($rest:tt)+) => {
+        ::core::compile_error!("`.zip({...})` requires a second set with the same number of values")
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro collects the steps of a chain of `.map(...)` and `.filter(...)` transformations into a list.
+/// The chain ends with either `.satisfy(...)`, which is collected as a final `.map(...)` followed by `== true`,
+/// or a comparison, after which the steps are applied to the values using `__pipeline_quantify`.
+/// A step that is not a closure is evaluated once and bound to a variable before any of the values are evaluated.
+/// Closures are passed on as they are, so that the compiler knows the type of their argument when it checks their body.
+macro_rules! __pipeline_collect {
+    (@step $quantifier:ident, $lh_sides:tt, [$($steps:tt)*], $kind:ident (| $($closure:tt)*), $($chain:tt)+) => {
+        $crate::__pipeline_collect!($quantifier, $lh_sides, [$($steps)* ($kind | $($closure)*)], $($chain)+)
+    };
+    (@step $quantifier:ident, $lh_sides:tt, [$($steps:tt)*], $kind:ident (move $($closure:tt)*), $($chain:tt)+) => {
+        $crate::__pipeline_collect!($quantifier, $lh_sides, [$($steps)* ($kind move $($closure)*)], $($chain)+)
+    };
+    (@step $quantifier:ident, $lh_sides:tt, [$($steps:tt)*], $kind:ident ($func:expr), $($chain:tt)+) => {
+        {
+            let step = &$func;
+            $crate::__pipeline_collect!($quantifier, $lh_sides, [$($steps)* ($kind step)], $($chain)+)
+        }
+    };
+    ($quantifier:ident, $lh_sides:tt, $steps:tt, .map($($func:tt)+) $($chain:tt)+) => {
+        $crate::__pipeline_collect!(@step $quantifier, $lh_sides, $steps, map ($($func)+), $($chain)+)
+    };
+    ($quantifier:ident, $lh_sides:tt, $steps:tt, .filter($($func:tt)+) $($chain:tt)+) => {
+        $crate::__pipeline_collect!(@step $quantifier, $lh_sides, $steps, filter ($($func)+), $($chain)+)
+    };
+    ($quantifier:ident, $lh_sides:tt, $steps:tt, .satisfy($($func:tt)+)) => {
+        $crate::__pipeline_collect!(@step $quantifier, $lh_sides, $steps, map ($($func)+), == true)
+    };
+    ($quantifier:ident, $lh_sides:tt, $steps:tt, $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__pipeline_quantify!($quantifier, $lh_sides, $steps, $operator $rhs)
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro applies a pipeline of steps to every value and combines the results lazily from left to right.
+/// A value that is skipped by a filter evaluates to `false` for `any` and `none` and to `true` for `all`,
+/// so that it does not influence the result.
+macro_rules! __pipeline_quantify {
+    (any, [$($lh_sides:expr),+], $steps:tt, $operator:tt $rhs:expr) => {
+        $( ('pipeline: { $crate::__pipeline_element!('pipeline, false, $lh_sides, $steps, $operator $rhs) }) )||+
+    };
+    (all, [$($lh_sides:expr),+], $steps:tt, $operator:tt $rhs:expr) => {
+        $( ('pipeline: { $crate::__pipeline_element!('pipeline, true, $lh_sides, $steps, $operator $rhs) }) )&&+
+    };
+    (none, [$($lh_sides:expr),+], $steps:tt, $operator:tt $rhs:expr) => {
+        $( !('pipeline: { $crate::__pipeline_element!('pipeline, false, $lh_sides, $steps, $operator $rhs) }) )&&+
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro applies the steps of a pipeline to a single value. If a filter rejects the value, it breaks out of
+/// the enclosing block labeled `$label` with the value `$skipped`.
+macro_rules! __pipeline_element {
+    ($label:lifetime, $skipped:literal, $value:expr, [(map $func:expr) $($steps:tt)*], $($end:tt)+) => {
+        $crate::__pipeline_element!($label, $skipped, $crate::pipeline::map($value, $func), [$($steps)*], $($end)+)
+    };
+    ($label:lifetime, $skipped:literal, $value:expr, [(filter $func:expr) $($steps:tt)*], $($end:tt)+) => {
+        {
+            let value = $value;
+            if !$crate::pipeline::filter(&value, $func) {
+                break $label $skipped;
+            }
+            $crate::__pipeline_element!($label, $skipped, value, [$($steps)*], $($end)+)
+        }
+    };
+    ($label:lifetime, $skipped:literal, $value:expr, [], $operator:tt $rhs:expr) => {
+        ($value $operator $rhs)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro combines `Option<bool>` values using [three-valued (Kleene) logic](https://en.wikipedia.org/wiki/Three-valued_logic).
+/// The values are evaluated from left to right until a value equal to `Some($decisive)` is encountered,
+/// in which case the macro evaluates to `Some($decided)`. If no value is decisive, the macro evaluates to
+/// `Some($otherwise)` if all values were known and to `None` otherwise.
+macro_rules! __kleene_quantify {
+    ($decisive:literal => $decided:literal, $otherwise:literal; $($values:expr),+) => {
+        {
+            let mut unknown = false;
+            'kleene: {
+                $(
+                    match $values {
+                        ::core::option::Option::Some($decisive) => break 'kleene ::core::option::Option::Some($decided),
+                        ::core::option::Option::Some(_) => {}
+                        ::core::option::Option::None => unknown = true,
+                    }
+                )+
+                if unknown {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some($otherwise)
+                }
+            }
+        }
+    };
+}
+
 /// Compare all values in a set to a common right hand side and decide whether the comparison returns `true` for *any of the values* in the set.
 ///
 /// # Lazy Evaluation
@@ -93,119 +522,2768 @@ macro_rules! __check_operator {
 /// assert!(any_of!({12,14,5}.satisfy(is_prime_number)));
 /// ```
 ///
+/// ## Chains of Transformations
 ///
-#[macro_export]
-macro_rules! any_of {
-    // variant with a predicate (does not use a comparison operator and rhs)
-    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
-        any_of!({$($lh_sides),+}.map($($func)+)==true)
-    };
-
-    // variant with a transformation of the set
-    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
-        {
-            $crate::__check_operator!($operator);
-            //by fixing this here, we have more type deduction powers but also less
-            //flexibility in generic arguments. We could also pass the expanded tt func to a single
-            //tt in a submacro (by putting (...) around it) and then use that function, which is more
-            //powerful when passing generic functions, but less intuitive when passing lambdas
-            //so this map is more akin to a map in a collection. The other is more akin to a C++
-            //transform of a heterogeneous collection. For that we might want to pass a path or ident
-            //instead of the token tree. Because the token tree is just a trick to get lambdas
-            //as well. But since the other way isn't great for lambdas anyway we can just skip it.
-            let map_func = $($func)+;
-            $( (map_func($lh_sides) $operator $rhs) )||+
-        }
-    };
-
-    //variant without map (requires a comparison operator and rhs)
-    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr)=> {
-        {
-            $crate::__check_operator!($operator);
-            $( ($lh_sides $operator $rhs) )||+
-        }
-    };
-}
-
-/// Compare all values in a set to a common right hand side and decide whether the comparison returns `true` for *all of the values* in the set.
+/// We can chain any number of `.map(...)` and `.filter(...)` steps, which are applied to each value in order and end with either
+/// `.satisfy(...)` or a comparison, e.g. `any_of!({...}.map(f).filter(p).map(g) > rhs)`. Each value goes through the whole chain
+/// before the next value is processed, so the evaluation stays lazy. In contrast to a single `.map(...)`, the values can change their
+/// type from step to step and the arguments of closures are inferred from the output of the previous step. The transformations
+/// are invoked once per value like for a single `.map(...)`, while the expressions that produce them, like `make_step()` in
+/// `.map(make_step())`, are evaluated exactly once before the first value.
 ///
-/// # Usage
-/// The usage is analogous to the [any_of](crate::any_of) macro and is documented in more detail there.
-/// Just like `any_of`, this macro also performs lazy evaluation.
+/// Like [Iterator::filter], `.filter(...)` passes a reference to the value to the predicate. Values for which the predicate
+/// returns `false` are skipped and the quantifiers treat them as if they were not part of the set. If all values are skipped,
+/// the usual rules of [vacuous truth](https://en.wikipedia.org/wiki/Vacuous_truth) apply, just like for the methods
+/// of iterators: `any_of` evaluates to `false`, while `all_of` and `none_of` evaluate to `true`.
 ///
-/// ## Examples
-/// The following examples show how to use the macro.
 /// ```
+/// # use fluent_comparisons_macros::{any_of,all_of,none_of};
+/// let parse = |s: &str| s.parse::<i32>().unwrap();
+/// let (a, b, c) = ("-3", "12", "4");
+/// // the following assertions hold
+/// assert!(!all_of!({a,b,c}.map(parse).map(i32::abs).satisfy(|x| x < 10)));
+/// assert!(any_of!({a,b,c}.map(parse).filter(|x| *x > 0).map(|x| 2 * x) == 8));
+/// assert!(none_of!({a,b,c}.filter(|s| s.len() == 1).map(parse) > 4));
+/// // vacuous truth if all values are skipped
+/// assert!(!any_of!({a,b,c}.map(parse).filter(|x| *x > 100) > 0));
+/// assert!(all_of!({a,b,c}.map(parse).filter(|x| *x > 100) < 0));
+/// assert!(none_of!({a,b,c}.map(parse).filter(|x| *x > 100) > 0));
+/// ```
+///
+/// ## Usage with Indices and a Second Set
+///
+/// Append `.enumerate()` to the set to pair each value with its index, so that transformations and predicates receive
+/// `(index, value)` tuples like for [Iterator::enumerate]. The indices are `usize` literals in the generated code, so there is no
+/// runtime counter. This works for sets of up to 64 values. Similarly, `.zip({...})` pairs each value with the value at the same
+/// position in a second set, which must have the same number of values. Both can be followed by anything that can follow a set,
+/// including each other.
+///
+/// ```
+/// # use fluent_comparisons_macros::{any_of,all_of};
+/// let (a, b, c) = (5, 15, 22);
+/// // the following assertions hold
+/// assert!(all_of!({a,b,c}.enumerate().satisfy(|(i, x)| x < (i + 1) * 10)));
+/// assert!(any_of!({a,b,c}.enumerate().map(|(i, x)| x - i) == 14));
+/// assert!(all_of!({a,b,c}.zip({6,16,23}).satisfy(|(x, limit)| x < limit)));
+/// assert!(all_of!({a,b,c}.enumerate().zip({1,2,3}).map(|((i, _), y)| y - i) == 1));
+/// ```
+///
+/// Zipping sets of different sizes fails to compile with the message
+/// ``"`.zip({...})` requires a second set with the same number of values"``:
+/// ```compile_fail
 /// # use fluent_comparisons_macros::all_of;
+/// let (a, b, c) = (5, 15, 22);
+/// all_of!({a,b,c}.zip({6,16}).satisfy(|(x, limit)| x < limit));
+/// ```
 ///
-/// let square = |val|val*val;
-/// // the following assertion holds
-/// assert!(all_of!({4+4+1,square(7*2),120_i32.pow(2)}>0));
+/// ## Usage with Borrowing Transformations
+///
+/// The transformation given to `.map(...)` or `.satisfy(...)` takes the values by value, which moves values that are not `Copy`.
+/// Use `.map_ref(...)` or `.satisfy_ref(...)` instead to pass a reference to each value, so that the values stay usable
+/// after the macro. The values can be temporaries with different lifetimes. The type of a closure argument is inferred from the
+/// first value, but a closure can not return data borrowed from its argument. Use a function like `String::as_str` for that.
 ///
-/// let v = vec![1, 2,3,4,5];
-/// // the following assertion holds
-/// assert!(all_of!( {square(2),v.len() as i32,2,1+1,"hello".len() as i32} <= v.len() as i32));
 /// ```
-#[macro_export]
-macro_rules! all_of {
-
-    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
-        all_of!({$($lh_sides),+}.map($($func)+)==true)
-    };
-
-    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
-        {
-            $crate::__check_operator!($operator);
-            let map_func = $($func)+;
-            $( (map_func($lh_sides) $operator $rhs) )&&+
-        }
-    };
-
-    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr)=> {
-        {
-            $crate::__check_operator!($operator);
-            $( ($lh_sides $operator $rhs) )&&+
-        }
-    };
-}
-
-/// Compare all values in a set to a common right hand side and decide whether the comparison returns `true` for *none of the values* in the set.
+/// # use fluent_comparisons_macros::any_of;
+/// let (first, second) = (String::from("hello"), String::from("world!"));
+/// // the following assertions hold
+/// assert!(any_of!({first, second, String::from("!")}.map_ref(|s| s.len()) > 5));
+/// assert!(any_of!({first, second}.map_ref(String::as_str) == "hello"));
+/// assert!(!any_of!({first, second}.satisfy_ref(String::is_empty)));
+/// // the strings were not moved
+/// assert_eq!(first + &second, "helloworld!");
+/// ```
 ///
-/// # Usage
-/// The usage is analogous to the [any_of](crate::any_of) macro and is documented in more detail there.
-/// Just like `any_of`, this macro also performs lazy evaluation.
+/// ## Comparisons using a Custom Ordering
+///
+/// To compare the values with respect to a relation other than their implementations of `PartialEq` and `PartialOrd`, insert
+/// `.by(cmp)` or `.by_key(key)` in front of the operator. Like [slice::sort_by], `.by(cmp)` calls `cmp(&value, &rhs)`, which must return an
+/// [Ordering](core::cmp::Ordering), and interprets the operator through that ordering. Like [slice::sort_by_key], `.by_key(key)` compares
+/// `key(&value)` with `key(&rhs)` using the operator. The evaluation is lazy as usual. If the argument is a closure, its argument types are inferred from the first value,
+/// so the right hand side must be of the same type as the values. Comparing case-insensitively is a common use case, for which
+/// [ignore_ascii_case](crate::by::ignore_ascii_case) compares strings without allocating.
 ///
-/// ## Examples
-/// The following examples show how to use the macro.
 /// ```
-/// # use fluent_comparisons_macros::none_of;
+/// # use fluent_comparisons_macros::{any_of,all_of,by::ignore_ascii_case};
+/// # use core::cmp::Ordering;
+/// struct Version { major: u32, minor: u32 }
+/// fn version_cmp(lhs: &Version, rhs: &Version) -> Ordering {
+///     (lhs.major, lhs.minor).cmp(&(rhs.major, rhs.minor))
+/// }
+/// let (p, q) = (Version { major: 1, minor: 4 }, Version { major: 2, minor: 0 });
+/// let (a, b) = ("Guest", "ADMIN");
+/// // the following assertions hold
+/// assert!(all_of!({p,q}.by(version_cmp) >= Version { major: 1, minor: 2 }));
+/// assert!(any_of!({p,q}.by(|lhs, rhs| lhs.minor.cmp(&rhs.minor)) == Version { major: 7, minor: 0 }));
+/// assert!(any_of!({a,b}.by_key(str::to_lowercase) == "admin"));
+/// assert!(any_of!({a,b}.by(ignore_ascii_case) == "guest"));
+/// ```
 ///
-/// let square = |val|val*val;
-/// // the following assertion holds
-/// assert!(none_of!({4+4+1,square(7*2),120_i32.pow(2)}<0));
+/// ## Membership Tests against Sets of Literals
+///
+/// A common use case is testing whether a value is one of many constants, like a protocol code. Writing this as
+/// `any_of!({1,5,9,/*...*/}==x)` produces a chain of comparisons that grows linearly with the number of values.
+/// If all values are literals, we can instead write the value first: `any_of!(x == {1,5,9,/*...*/})`. The value can be
+/// any expression, is evaluated only once and its type must match the type of the literals (e.g. `&str` for string literals).
+/// For integer and char literals, this expands to a single `matches!(x, 1|5|9|...)`, which the compiler turns into jump tables or
+/// bit tests. String literals are sorted into a [SortedStrs](crate::membership::SortedStrs) table at compile time instead,
+/// where the value is looked up using binary search. The negated forms are `none_of!(x == {...})` and `all_of!(x != {...})`.
+/// Since strings can't be matched in const contexts, use a [SortedStrs](crate::membership::SortedStrs) table directly there.
 ///
-/// let v = vec![1, 2,3,4,5];
-/// // the following assertion holds
-/// assert!(none_of!( {square(2),v.len() as i32,2,1+1,"hello".len() as i32} > v.len() as i32));
 /// ```
-#[macro_export]
-macro_rules! none_of {
-    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
-        none_of!({$($lh_sides),+}.map($($func)+)==true)
-    };
-
-    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
-        {
-            $crate::__check_operator!($operator);
+/// # use fluent_comparisons_macros::{any_of,all_of,none_of};
+/// let (code, letter, method) = (404, 'c', String::from("POST"));
+/// // the following assertions hold
+/// assert!(any_of!(code == {200, 204, 301, 304, 404, 500}));
+/// assert!(none_of!(code + 1 == {200, 204, 301, 304, 404, 500}));
+/// assert!(all_of!(letter != {'a', 'e', 'i', 'o', 'u'}));
+/// assert!(any_of!(method.as_str() == {"PUT", "GET", "HEAD", "POST", "PATCH", "DELETE"}));
+/// ```
+///
+/// ## Const Evaluation
+///
+/// The macros can be used in const contexts, like `const` items or const generic arguments, as long as the comparisons
+/// can be evaluated at compile time. This is the case for comparisons of primitive types with the basic form, the eager form,
+/// the membership tests against integer, char and bool literals, and the `.map(...)` and `.satisfy(...)` forms if the transformation is a path to a `const fn`.
+/// Closures cannot be called in const contexts, so move the transformation into a `const fn` instead. The forms using `.total()`, `.strict()`, `.kleene()`
+/// comparisons or lifting rely on trait methods and cannot be evaluated in const contexts.
+///
+/// To assert a condition at compile time, use [const_assert_all_of](crate::const_assert_all_of),
+/// [const_assert_any_of](crate::const_assert_any_of) or [const_assert_none_of](crate::const_assert_none_of).
+///
+/// ```
+/// # use fluent_comparisons_macros::any_of;
+/// const LIMIT: u32 = 10;
+/// const fn square(x: u32) -> u32 { x * x }
+/// const fn is_even(x: u32) -> bool { x % 2 == 0 }
+/// // the following constants evaluate to true
+/// const BASIC: bool = any_of!({1,5,12} > LIMIT);
+/// const MAPPED: bool = any_of!({1,2,4}.map(square) > LIMIT);
+/// const SATISFIED: bool = any_of!({1,2,5}.satisfy(is_even));
+/// const MEMBERSHIP: bool = any_of!(LIMIT == {2, 10, 20});
+/// assert!(BASIC && MAPPED && SATISFIED && MEMBERSHIP);
+/// ```
+///
+/// ## Eager Evaluation
+///
+/// Lazy evaluation requires a branch for every comparison, and for cheap numeric comparisons in hot loops
+/// the branches can cost more than the comparisons themselves. Prefix the set with `eager` to evaluate all comparisons
+/// and combine them with the non short-circuiting operators `|` and `&` instead, which allows the compiler to emit
+/// branch-free or even vectorized code. This works for the basic syntax as well as for `.map(...)` and `.satisfy(...)`.
+///
+/// The result is always the same as for the lazy macros, but the side effects are not: *all* expressions in the set
+/// are evaluated and all transformations are applied, even if the result was already decided by a previous
+/// comparison. So only use this for expressions that are cheap and free of side effects.
+///
+/// ```
+/// # use fluent_comparisons_macros::any_of;
+/// let (a, b, c, d) = (1, 5, 3, 7);
+/// // the following assertions hold
+/// assert!(any_of!(eager {a,b,c,d} > 6));
+/// assert!(any_of!(eager {a,b,c,d}.map(|x|x%2) == 1));
+/// assert!(!any_of!(eager {a,b,c,d}.satisfy(|x|x>7)));
+/// ```
+///
+/// ## Comparing Homogeneous Sets of Numbers using SIMD
+///
+/// If all values on the left hand side are primitive numbers of the same type, prefix the set with `simd` to compare
+/// all of them at once using the functions in the [simd](crate::simd) module. This uses SIMD comparisons
+/// followed by a reduction of the resulting mask, which is much faster than a chain of scalar comparisons for
+/// e.g. 8 or 16 values. Like eager evaluation, this evaluates and compares all values. It also works with `.map(...)`,
+/// which is applied to all values before the comparison.
+///
+/// ```
+/// # use fluent_comparisons_macros::any_of;
+/// let (a, b, c, d) = (1.0_f32, 5., 3., 7.);
+/// // the following assertions hold
+/// assert!(any_of!(simd {a,b,c,d} > 6.));
+/// assert!(!any_of!(simd {a,b,c,d}.map(|x:f32|x.sqrt()) > 3.));
+/// ```
+///
+/// ## Total Order Comparisons
+///
+/// The comparison operators on floating point numbers only implement a partial order, since `NaN` is
+/// unordered with respect to every value. This means that theorems like `all_of!({...}<x) == none_of!({...}>=x)`
+/// do not hold if one of the values is `NaN`. Insert `.total()` in front of the operator (after the `.map(...)`, if any) to
+/// compare the values with respect to the [total order](crate::total::TotalOrder) instead. For floating point numbers
+/// this uses `total_cmp`, which sorts positive `NaN` above positive infinity and negative `NaN`
+/// below negative infinity. For this modifier the values and the right hand side must be of the same type.
+///
+/// ```
+/// # use fluent_comparisons_macros::{any_of,none_of};
+/// let nan = f64::NAN;
+/// // NaN compares unordered to 1.
+/// assert!(!any_of!({nan,0.5}>1.));
+/// assert!(none_of!({nan,0.5}>1.));
+/// // the total order puts NaN above all numbers
+/// assert!(any_of!({nan,0.5}.total()>1.));
+/// assert!(any_of!({nan,0.5}.map(|x|2.*x).total()>1.));
+/// ```
+///
+/// ## Strict Comparisons
+///
+/// Insert `.strict()` in front of the operator (after the `.map(...)`, if any) to detect unordered comparisons instead.
+/// The comparisons are performed using [PartialOrd::partial_cmp] and the macro evaluates to an
+/// `Option<bool>`, which is `None` if a comparison was unordered and `Some(result)` otherwise.
+/// The evaluation is still lazy, so only the comparisons that were performed until the result was
+/// decided are checked.
+///
+/// ```
+/// # use fluent_comparisons_macros::any_of;
+/// let nan = f64::NAN;
+/// assert_eq!(any_of!({0.5,2.}.strict()>1.), Some(true));
+/// assert_eq!(any_of!({0.5,nan,2.}.strict()>1.), None);
+/// // evaluation stops before the NaN is compared
+/// assert_eq!(any_of!({2.,nan}.strict()>1.), Some(true));
+/// assert_eq!(any_of!({0.25,0.5}.map(|x|2.*x).strict()>1.), Some(false));
+/// ```
+///
+/// ## Comparing the Contents of Options and Results
+///
+/// Comparing `Option` values directly compares the `Option`s themselves, e.g. `None < Some(x)` for all `x`,
+/// which is rarely what we want. The macros can lift the comparison to the contents of `Option` and `Result` values
+/// (or any type implementing [Lift](crate::lift::Lift)) instead. We can choose what happens if a value is `None` or `Err`:
+///
+/// * `any_of!({...}? operator rhs)` evaluates the values from left to right and short-circuits the whole expression
+///   to `None` (or the `Err`) when it encounters a missing value, just like the `?` operator. Otherwise it evaluates to
+///   `Some(result)` (or `Ok(result)`). All values must be `Option`s, or all values must be `Result`s with the same error type.
+/// * `any_of!({...}.lift_or(default) operator rhs)` evaluates to a `bool`, where the comparison for a missing value
+///   counts as `default`. So `false` means that missing values never match and `true` means that they always match.
+///
+/// Both forms can be combined with `.map(...)` and `.satisfy(...)`, which are then applied to the contained values.
+///
+/// ```
+/// # use fluent_comparisons_macros::any_of;
+/// let (a, b, c) = (Some(1), None, Some(7));
+/// // this compares the options and None < Some(5)
+/// assert!(any_of!({a,b,c}<Some(5)));
+/// // the following assertions hold
+/// assert_eq!(any_of!({a,c}? > 5), Some(true));
+/// assert_eq!(any_of!({a,b,c}? > 5), None);
+/// assert!(any_of!({a,b,c}.lift_or(false) > 5));
+/// assert!(!any_of!({a,b}.lift_or(false) > 5));
+/// assert!(any_of!({a,b}.lift_or(true) > 5));
+/// assert!(any_of!({a,b,c}.lift_or(false).map(|x|x*x) == 49));
+///
+/// let (x, y) = ("1".parse::<i32>(), "two".parse::<i32>());
+/// assert!(any_of!({x.clone(),y.clone()}? == 2).is_err());
+/// assert_eq!(any_of!({x,y}? == 1), Ok(true));
+/// ```
+///
+/// ## Three-Valued Logic
+///
+/// Sometimes the truth value of a predicate is unknown, which we represent as an `Option<bool>`. The macros
+/// combine such values using [three-valued (Kleene) logic](https://en.wikipedia.org/wiki/Three-valued_logic).
+/// For `any_of` the result is `Some(true)` if any value is known to be `true`, `Some(false)` if all values are known to be `false`,
+/// and `None` otherwise. For `all_of` and `none_of` the results are defined accordingly. The evaluation stops as soon as the result is decided.
+/// There are three ways to obtain the values:
+///
+/// * `any_of!({...}.kleene())` uses the expressions in the set directly, which must be of type `Option<bool>`.
+/// * `any_of!({...}.satisfy_kleene(/*predicate f:x -> Option<bool>*/))` applies a predicate to the expressions.
+/// * `any_of!({...}.kleene() operator rhs)` (optionally after a `.map(...)`) compares the values using [PartialOrd::partial_cmp],
+///   where unordered comparisons are unknown. In contrast to `.strict()`, an unordered comparison does not decide the result.
+///
+/// ```
+/// # use fluent_comparisons_macros::any_of;
+/// let sensor_reading = |id: usize| if id < 3 { Some(id as f64) } else { None };
+/// let is_hot = |id: usize| sensor_reading(id).map(|value| value > 1.5);
+/// // the following assertions hold
+/// assert_eq!(any_of!({0,3,2}.satisfy_kleene(is_hot)), Some(true));
+/// assert_eq!(any_of!({0,3,1}.satisfy_kleene(is_hot)), None);
+/// assert_eq!(any_of!({0,1}.satisfy_kleene(is_hot)), Some(false));
+/// assert_eq!(any_of!({Some(false),None,Some(true)}.kleene()), Some(true));
+/// assert_eq!(any_of!({f64::NAN,2.}.kleene()>1.), Some(true));
+/// ```
+///
+/// ## Placeholders
+///
+/// To obtain a reusable predicate, e.g. for [Iterator::filter] or to store it in a struct, write the placeholder `_` instead of the right hand side.
+/// Then `any_of!({a,b,c} > _)` evaluates to the closure `move |rhs| any_of!({a,b,c} > rhs)`. Since [Iterator::filter] passes references, the
+/// placeholder `*_` compares with the dereferenced argument instead. This also helps if the argument would otherwise be inferred as an unsized type
+/// like `str`, e.g. for `.by_key(str::len)`. The placeholder can follow a comparison with at most one modifier like `.map(...)`
+/// in front of the operator. The set is evaluated lazily for each invocation of the closure as usual.
+///
+/// Conversely, the set can be a placeholder. Then `all_of!({_} < limit)` evaluates to a closure that takes the set of values as its argument,
+/// which can be an array, a slice, or a tuple of values of the same type (see [Values](crate::values::Values)). If the type of the argument can not
+/// be inferred, give it as `{_: [u32; 3]}`. The rest of the invocation can be any form that evaluates to a `bool`. The right hand side is evaluated
+/// once per invocation of the closure, before the values are compared lazily from left to right.
+///
+/// Both closures move the variables they use, like any `move` closure.
+///
+/// ```
+/// # use fluent_comparisons_macros::{any_of,all_of};
+/// let (a, b, c) = (1, 5, 9);
+/// let is_exceeded = any_of!({a,b,c} > _);
+/// // the following assertions hold
+/// assert!(is_exceeded(8));
+/// let matching: Vec<i32> = vec![0, 3, 10].into_iter().filter(any_of!({a,b,c} > *_)).collect();
+/// assert_eq!(matching, [0, 3]);
+///
+/// let limit = 10;
+/// let is_small = all_of!({_: [i32; 3]}.map(|x|x*x) < limit);
+/// assert!(is_small([1, 2, 3]));
+/// assert!(!is_small([1, 2, 4]));
+/// assert!(all_of!({_} < limit)((1, 2, 3)));
+/// ```
+///
+/// ## Spreading Values into the Set
+///
+/// To quantify over values that are only known at runtime, write `{..values}` for the set, where `values` is anything that
+/// implements [IntoIterator], like an array, a `Vec` or an iterator. The rest of the invocation works like for the set placeholder
+/// `{_}`, i.e. the right hand side and the functions given to modifiers like `.map(...)` are evaluated once and the values are
+/// compared lazily from left to right.
+///
+/// With the `derive` feature, `#[derive(Quantify)]` implements `IntoIterator` for references to a struct whose fields are all of the same type,
+/// so that `all_of!({..&s} < &90)` compares all fields of `s`, including fields that are added later. It also generates methods like
+/// `s.fields_in_group("load")` for the fields marked with `#[quantify(group = "load")]`, which can be spread just the same.
+///
+/// ```
+/// # use fluent_comparisons_macros::{any_of,all_of};
+/// let readings = vec![1.5, 2.0, 7.5];
+/// // the following assertions hold
+/// assert!(any_of!({..&readings} > &5.));
+/// assert!(all_of!({..readings.iter().copied()}.map(f64::sqrt) < 3.));
+/// assert!(all_of!({..[1, 2, 3]} < 4));
+/// ```
+///
+/// ## Matching Patterns
+///
+/// With the `regex` feature, `any_of!({...}.matches_regex(re))` decides whether any of the values matches the regular expression `re`.
+/// With the `glob` feature, `any_of!({...}.matches_glob(pattern))` does the same for a glob pattern like `"*.rs"`. The pattern is either
+/// given compiled or as a string, which is compiled once per invocation of the macro rather than once per value. For a string, the macro
+/// evaluates to a `Result` with the error of compiling the pattern if it is invalid, in which case the values are not evaluated. The values can be of
+/// different types, as long as they implement `AsRef<str>` for regular expressions or `AsRef<Path>` for glob patterns. A single `.map(...)`
+/// can be applied to the values first. The `pattern` module documents this in more detail.
+///
+#[macro_export]
+macro_rules! any_of {
+    // placeholder variants that evaluate to a closure
+    ({_ $(: $values_type:ty)?} $($rest:tt)+) => {
+        move |values $(: $values_type)?| $crate::__placeholder_set!(any_of, any_of, values, [], $($rest)+)
+    };
+
+    // variants that spread the values of an iterable into the set
+    ({..$values:expr} $($rest:tt)+) => {
+        {
+            let values = $crate::values::Spread(::core::iter::IntoIterator::into_iter($values));
+            $crate::__placeholder_set!(any_of, any_of, values, [], $($rest)+)
+        }
+    };
+
+    // variants that match the values against a regular expression or a glob pattern
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).matches_regex($pattern:expr) ) => {
+        $crate::__with_regex!($pattern, pattern => $crate::any_of!({$($lh_sides),+}.map($($func)+).satisfy(|value| $crate::pattern::is_regex_match(&pattern, value))))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.matches_regex($pattern:expr) ) => {
+        $crate::__with_regex!($pattern, pattern => $crate::any_of!({$($crate::pattern::is_regex_match(&pattern, $lh_sides)),+} == true))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).matches_glob($pattern:expr) ) => {
+        $crate::__with_glob!($pattern, pattern => $crate::any_of!({$($lh_sides),+}.map($($func)+).satisfy(|value| $crate::pattern::is_glob_match(&pattern, value))))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.matches_glob($pattern:expr) ) => {
+        $crate::__with_glob!($pattern, pattern => $crate::any_of!({$($crate::pattern::is_glob_match(&pattern, $lh_sides)),+} == true))
+    };
+
+    ({$($lh_sides:expr),+ $(,)?} $operator:tt _) => {
+        move |rhs| $crate::any_of!({$($lh_sides),+} $operator rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?} $operator:tt *_) => {
+        move |rhs: &_| $crate::any_of!({$($lh_sides),+} $operator *rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?}.$method:ident $args:tt $operator:tt _) => {
+        move |rhs| $crate::any_of!({$($lh_sides),+}.$method $args $operator rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?}.$method:ident $args:tt $operator:tt *_) => {
+        move |rhs: &_| $crate::any_of!({$($lh_sides),+}.$method $args $operator *rhs)
+    };
+
+    // variants that compare homogeneous sets of numbers using SIMD
+    (simd {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
             let map_func = $($func)+;
-            $( !(map_func($lh_sides) $operator $rhs) )&&+
+            $crate::__simd_compare!($crate::simd::any_of_simd(&[$(map_func($lh_sides)),+]), $operator, $rhs)
+        }
+    };
+
+    (simd {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__simd_compare!($crate::simd::any_of_simd(&[$($lh_sides),+]), $operator, $rhs)
+        }
+    };
+
+    // eager variants that evaluate all comparisons
+    (eager {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::any_of!(eager {$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    (eager {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func($lh_sides) $operator $rhs) )|+
+        }
+    };
+
+    (eager {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( ($lh_sides $operator $rhs) )|+
+        }
+    };
+
+    // variants that pair the values with their indices or with the values of a second set
+    ( {$($lh_sides:expr),+ $(,)?}.enumerate() $($rest:tt)+ ) => {
+        $crate::__enumerate!(any_of, {$($lh_sides),+} $($rest)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.zip({$($other_sides:expr),+ $(,)?}) $($rest:tt)+ ) => {
+        $crate::__zip!(any_of, [], [$($lh_sides),+], [$($other_sides),+], $($rest)+)
+    };
+
+    // variant with a predicate (does not use a comparison operator and rhs)
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::any_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy_kleene($($func:tt)+) ) => {
+        {
+            let map_func = $($func)+;
+            $crate::__kleene_quantify!(true => true, false; $(map_func($lh_sides)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).kleene() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $crate::__kleene_quantify!(true => true, false; $($crate::__strict_compare!(map_func($lh_sides), $operator, $rhs)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).total() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( ($crate::total::Total(&map_func($lh_sides)) $operator $crate::total::Total(&$rhs)) )||+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).strict() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'strict: {
+                $(
+                    match $crate::__strict_compare!(map_func($lh_sides), $operator, $rhs) {
+                        ::core::option::Option::Some(false) => {}
+                        decided => break 'strict decided,
+                    }
+                )+
+                ::core::option::Option::Some(false)
+            }
+        }
+    };
+
+    // variants with a chain of transformations and filters
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) . $($chain:tt)+ ) => {
+        $crate::__pipeline_collect!(any, [$($lh_sides),+], [], .map($($func)+) . $($chain)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.filter($($func:tt)+) $($chain:tt)+ ) => {
+        $crate::__pipeline_collect!(any, [$($lh_sides),+], [], .filter($($func)+) $($chain)+)
+    };
+
+    // variant with a transformation of the set
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            //by fixing this here, we have more type deduction powers but also less
+            //flexibility in generic arguments. We could also pass the expanded tt func to a single
+            //tt in a submacro (by putting (...) around it) and then use that function, which is more
+            //powerful when passing generic functions, but less intuitive when passing lambdas
+            //so this map is more akin to a map in a collection. The other is more akin to a C++
+            //transform of a heterogeneous collection. For that we might want to pass a path or ident
+            //instead of the token tree. Because the token tree is just a trick to get lambdas
+            //as well. But since the other way isn't great for lambdas anyway we can just skip it.
+            let map_func = $($func)+;
+            $( (map_func($lh_sides) $operator $rhs) )||+
+        }
+    };
+
+    // variants that compare using a custom ordering or key
+    ( {$first:expr $(, $rest:expr)* $(,)?}.by(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let cmp_func = $crate::by::comparator(first, |$($closure)+);
+            $crate::__ordering_satisfies!(cmp_func(first, &$rhs), $operator) $( || $crate::__ordering_satisfies!(cmp_func(&$rest, &$rhs), $operator) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.by($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let cmp_func = $($func)+;
+            $( $crate::__ordering_satisfies!(cmp_func(&$lh_sides, &$rhs), $operator) )||+
+        }
+    };
+
+    ( {$first:expr $(, $rest:expr)* $(,)?}.by_key(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let key_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            (key_func(first) $operator key_func(&$rhs)) $( || (key_func(&$rest) $operator key_func(&$rhs)) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.by_key($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let key_func = $($func)+;
+            $( (key_func(&$lh_sides) $operator key_func(&$rhs)) )||+
+        }
+    };
+
+    // variants that pass references to the values to the transformation
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy_ref($($func:tt)+) ) => {
+        $crate::any_of!({$($lh_sides),+}.map_ref($($func)+)==true)
+    };
+
+    ( {$first:expr $(, $rest:expr)* $(,)?}.map_ref(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let map_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            (map_func(first) $operator $rhs) $( || (map_func(&$rest) $operator $rhs) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map_ref($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func(&$lh_sides) $operator $rhs) )||+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}?.satisfy($($func:tt)+) ) => {
+        $crate::any_of!({$($lh_sides),+}?.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}?.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'lifted: {
+                $(
+                    match $crate::lift::Lift::lift($lh_sides) {
+                        ::core::result::Result::Ok(value) => if (map_func(value) $operator $rhs) {
+                            break 'lifted $crate::lift::LiftOutput::from_bool(true);
+                        },
+                        ::core::result::Result::Err(output) => break 'lifted output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(false)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}? $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            'lifted: {
+                $(
+                    match $crate::lift::Lift::lift($lh_sides) {
+                        ::core::result::Result::Ok(value) => if (value $operator $rhs) {
+                            break 'lifted $crate::lift::LiftOutput::from_bool(true);
+                        },
+                        ::core::result::Result::Err(output) => break 'lifted output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(false)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr).satisfy($($func:tt)+) ) => {
+        $crate::any_of!({$($lh_sides),+}.lift_or($default).map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr).map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (match $crate::lift::Lift::lift($lh_sides) {
+                ::core::result::Result::Ok(value) => map_func(value) $operator $rhs,
+                ::core::result::Result::Err(_) => $default,
+            }) )||+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( (match $crate::lift::Lift::lift($lh_sides) {
+                ::core::result::Result::Ok(value) => value $operator $rhs,
+                ::core::result::Result::Err(_) => $default,
+            }) )||+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.kleene() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__kleene_quantify!(true => true, false; $($crate::__strict_compare!($lh_sides, $operator, $rhs)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.kleene() ) => {
+        $crate::__kleene_quantify!(true => true, false; $($lh_sides),+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.total() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( ($crate::total::Total(&$lh_sides) $operator $crate::total::Total(&$rhs)) )||+
         }
     };
 
+    ( {$($lh_sides:expr),+ $(,)?}.strict() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            'strict: {
+                $(
+                    match $crate::__strict_compare!($lh_sides, $operator, $rhs) {
+                        ::core::option::Option::Some(false) => {}
+                        decided => break 'strict decided,
+                    }
+                )+
+                ::core::option::Option::Some(false)
+            }
+        }
+    };
+
+    //variant without map (requires a comparison operator and rhs)
     ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr)=> {
         {
             $crate::__check_operator!($operator);
-            $( !($lh_sides $operator $rhs) )&&+
+            $( ($lh_sides $operator $rhs) )||+
         }
     };
+
+    // membership tests against sets of literals
+    ($($membership:tt)+) => {
+        $crate::__membership!([] == [] $($membership)+)
+    };
+}
+
+/// Compare all values in a set to a common right hand side and decide whether the comparison returns `true` for *all of the values* in the set.
+///
+/// # Usage
+/// The usage is analogous to the [any_of](crate::any_of) macro and is documented in more detail there.
+/// Just like `any_of`, this macro also performs lazy evaluation.
+///
+/// ## Examples
+/// The following examples show how to use the macro.
+/// ```
+/// # use fluent_comparisons_macros::all_of;
+///
+/// let square = |val|val*val;
+/// // the following assertion holds
+/// assert!(all_of!({4+4+1,square(7*2),120_i32.pow(2)}>0));
+///
+/// let v = vec![1, 2,3,4,5];
+/// // the following assertion holds
+/// assert!(all_of!( {square(2),v.len() as i32,2,1+1,"hello".len() as i32} <= v.len() as i32));
+/// ```
+#[macro_export]
+macro_rules! all_of {
+    // placeholder variants that evaluate to a closure
+    ({_ $(: $values_type:ty)?} $($rest:tt)+) => {
+        move |values $(: $values_type)?| $crate::__placeholder_set!(all_of, all_of, values, [], $($rest)+)
+    };
+
+    // variants that spread the values of an iterable into the set
+    ({..$values:expr} $($rest:tt)+) => {
+        {
+            let values = $crate::values::Spread(::core::iter::IntoIterator::into_iter($values));
+            $crate::__placeholder_set!(all_of, all_of, values, [], $($rest)+)
+        }
+    };
+
+    // variants that match the values against a regular expression or a glob pattern
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).matches_regex($pattern:expr) ) => {
+        $crate::__with_regex!($pattern, pattern => $crate::all_of!({$($lh_sides),+}.map($($func)+).satisfy(|value| $crate::pattern::is_regex_match(&pattern, value))))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.matches_regex($pattern:expr) ) => {
+        $crate::__with_regex!($pattern, pattern => $crate::all_of!({$($crate::pattern::is_regex_match(&pattern, $lh_sides)),+} == true))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).matches_glob($pattern:expr) ) => {
+        $crate::__with_glob!($pattern, pattern => $crate::all_of!({$($lh_sides),+}.map($($func)+).satisfy(|value| $crate::pattern::is_glob_match(&pattern, value))))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.matches_glob($pattern:expr) ) => {
+        $crate::__with_glob!($pattern, pattern => $crate::all_of!({$($crate::pattern::is_glob_match(&pattern, $lh_sides)),+} == true))
+    };
+
+    ({$($lh_sides:expr),+ $(,)?} $operator:tt _) => {
+        move |rhs| $crate::all_of!({$($lh_sides),+} $operator rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?} $operator:tt *_) => {
+        move |rhs: &_| $crate::all_of!({$($lh_sides),+} $operator *rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?}.$method:ident $args:tt $operator:tt _) => {
+        move |rhs| $crate::all_of!({$($lh_sides),+}.$method $args $operator rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?}.$method:ident $args:tt $operator:tt *_) => {
+        move |rhs: &_| $crate::all_of!({$($lh_sides),+}.$method $args $operator *rhs)
+    };
+
+    // variants that compare homogeneous sets of numbers using SIMD
+    (simd {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $crate::__simd_compare!($crate::simd::all_of_simd(&[$(map_func($lh_sides)),+]), $operator, $rhs)
+        }
+    };
+
+    (simd {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__simd_compare!($crate::simd::all_of_simd(&[$($lh_sides),+]), $operator, $rhs)
+        }
+    };
+
+    // eager variants that evaluate all comparisons
+    (eager {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::all_of!(eager {$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    (eager {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func($lh_sides) $operator $rhs) )&+
+        }
+    };
+
+    (eager {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( ($lh_sides $operator $rhs) )&+
+        }
+    };
+
+    // variants that pair the values with their indices or with the values of a second set
+    ( {$($lh_sides:expr),+ $(,)?}.enumerate() $($rest:tt)+ ) => {
+        $crate::__enumerate!(all_of, {$($lh_sides),+} $($rest)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.zip({$($other_sides:expr),+ $(,)?}) $($rest:tt)+ ) => {
+        $crate::__zip!(all_of, [], [$($lh_sides),+], [$($other_sides),+], $($rest)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::all_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy_kleene($($func:tt)+) ) => {
+        {
+            let map_func = $($func)+;
+            $crate::__kleene_quantify!(false => false, true; $(map_func($lh_sides)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).kleene() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $crate::__kleene_quantify!(false => false, true; $($crate::__strict_compare!(map_func($lh_sides), $operator, $rhs)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).total() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( ($crate::total::Total(&map_func($lh_sides)) $operator $crate::total::Total(&$rhs)) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).strict() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'strict: {
+                $(
+                    match $crate::__strict_compare!(map_func($lh_sides), $operator, $rhs) {
+                        ::core::option::Option::Some(true) => {}
+                        decided => break 'strict decided,
+                    }
+                )+
+                ::core::option::Option::Some(true)
+            }
+        }
+    };
+
+    // variants with a chain of transformations and filters
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) . $($chain:tt)+ ) => {
+        $crate::__pipeline_collect!(all, [$($lh_sides),+], [], .map($($func)+) . $($chain)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.filter($($func:tt)+) $($chain:tt)+ ) => {
+        $crate::__pipeline_collect!(all, [$($lh_sides),+], [], .filter($($func)+) $($chain)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func($lh_sides) $operator $rhs) )&&+
+        }
+    };
+
+    // variants that compare using a custom ordering or key
+    ( {$first:expr $(, $rest:expr)* $(,)?}.by(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let cmp_func = $crate::by::comparator(first, |$($closure)+);
+            $crate::__ordering_satisfies!(cmp_func(first, &$rhs), $operator) $( && $crate::__ordering_satisfies!(cmp_func(&$rest, &$rhs), $operator) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.by($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let cmp_func = $($func)+;
+            $( $crate::__ordering_satisfies!(cmp_func(&$lh_sides, &$rhs), $operator) )&&+
+        }
+    };
+
+    ( {$first:expr $(, $rest:expr)* $(,)?}.by_key(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let key_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            (key_func(first) $operator key_func(&$rhs)) $( && (key_func(&$rest) $operator key_func(&$rhs)) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.by_key($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let key_func = $($func)+;
+            $( (key_func(&$lh_sides) $operator key_func(&$rhs)) )&&+
+        }
+    };
+
+    // variants that pass references to the values to the transformation
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy_ref($($func:tt)+) ) => {
+        $crate::all_of!({$($lh_sides),+}.map_ref($($func)+)==true)
+    };
+
+    ( {$first:expr $(, $rest:expr)* $(,)?}.map_ref(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let map_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            (map_func(first) $operator $rhs) $( && (map_func(&$rest) $operator $rhs) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map_ref($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func(&$lh_sides) $operator $rhs) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}?.satisfy($($func:tt)+) ) => {
+        $crate::all_of!({$($lh_sides),+}?.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}?.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'lifted: {
+                $(
+                    match $crate::lift::Lift::lift($lh_sides) {
+                        ::core::result::Result::Ok(value) => if !(map_func(value) $operator $rhs) {
+                            break 'lifted $crate::lift::LiftOutput::from_bool(false);
+                        },
+                        ::core::result::Result::Err(output) => break 'lifted output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}? $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            'lifted: {
+                $(
+                    match $crate::lift::Lift::lift($lh_sides) {
+                        ::core::result::Result::Ok(value) => if !(value $operator $rhs) {
+                            break 'lifted $crate::lift::LiftOutput::from_bool(false);
+                        },
+                        ::core::result::Result::Err(output) => break 'lifted output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr).satisfy($($func:tt)+) ) => {
+        $crate::all_of!({$($lh_sides),+}.lift_or($default).map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr).map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (match $crate::lift::Lift::lift($lh_sides) {
+                ::core::result::Result::Ok(value) => map_func(value) $operator $rhs,
+                ::core::result::Result::Err(_) => $default,
+            }) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( (match $crate::lift::Lift::lift($lh_sides) {
+                ::core::result::Result::Ok(value) => value $operator $rhs,
+                ::core::result::Result::Err(_) => $default,
+            }) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.kleene() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__kleene_quantify!(false => false, true; $($crate::__strict_compare!($lh_sides, $operator, $rhs)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.kleene() ) => {
+        $crate::__kleene_quantify!(false => false, true; $($lh_sides),+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.total() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( ($crate::total::Total(&$lh_sides) $operator $crate::total::Total(&$rhs)) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.strict() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            'strict: {
+                $(
+                    match $crate::__strict_compare!($lh_sides, $operator, $rhs) {
+                        ::core::option::Option::Some(true) => {}
+                        decided => break 'strict decided,
+                    }
+                )+
+                ::core::option::Option::Some(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr)=> {
+        {
+            $crate::__check_operator!($operator);
+            $( ($lh_sides $operator $rhs) )&&+
+        }
+    };
+
+    // membership tests against sets of literals
+    ($($membership:tt)+) => {
+        $crate::__membership!([!] != [] $($membership)+)
+    };
+}
+
+/// Compare all values in a set to a common right hand side and decide whether the comparison returns `true` for *none of the values* in the set.
+///
+/// # Usage
+/// The usage is analogous to the [any_of](crate::any_of) macro and is documented in more detail there.
+/// Just like `any_of`, this macro also performs lazy evaluation.
+///
+/// ## Examples
+/// The following examples show how to use the macro.
+/// ```
+/// # use fluent_comparisons_macros::none_of;
+///
+/// let square = |val|val*val;
+/// // the following assertion holds
+/// assert!(none_of!({4+4+1,square(7*2),120_i32.pow(2)}<0));
+///
+/// let v = vec![1, 2,3,4,5];
+/// // the following assertion holds
+/// assert!(none_of!( {square(2),v.len() as i32,2,1+1,"hello".len() as i32} > v.len() as i32));
+/// ```
+#[macro_export]
+macro_rules! none_of {
+    // placeholder variants that evaluate to a closure
+    ({_ $(: $values_type:ty)?} $($rest:tt)+) => {
+        move |values $(: $values_type)?| $crate::__placeholder_set!(none_of, any_of, values, [], $($rest)+)
+    };
+
+    // variants that spread the values of an iterable into the set
+    ({..$values:expr} $($rest:tt)+) => {
+        {
+            let values = $crate::values::Spread(::core::iter::IntoIterator::into_iter($values));
+            $crate::__placeholder_set!(none_of, any_of, values, [], $($rest)+)
+        }
+    };
+
+    // variants that match the values against a regular expression or a glob pattern
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).matches_regex($pattern:expr) ) => {
+        $crate::__with_regex!($pattern, pattern => $crate::none_of!({$($lh_sides),+}.map($($func)+).satisfy(|value| $crate::pattern::is_regex_match(&pattern, value))))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.matches_regex($pattern:expr) ) => {
+        $crate::__with_regex!($pattern, pattern => $crate::none_of!({$($crate::pattern::is_regex_match(&pattern, $lh_sides)),+} == true))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).matches_glob($pattern:expr) ) => {
+        $crate::__with_glob!($pattern, pattern => $crate::none_of!({$($lh_sides),+}.map($($func)+).satisfy(|value| $crate::pattern::is_glob_match(&pattern, value))))
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.matches_glob($pattern:expr) ) => {
+        $crate::__with_glob!($pattern, pattern => $crate::none_of!({$($crate::pattern::is_glob_match(&pattern, $lh_sides)),+} == true))
+    };
+
+    ({$($lh_sides:expr),+ $(,)?} $operator:tt _) => {
+        move |rhs| $crate::none_of!({$($lh_sides),+} $operator rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?} $operator:tt *_) => {
+        move |rhs: &_| $crate::none_of!({$($lh_sides),+} $operator *rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?}.$method:ident $args:tt $operator:tt _) => {
+        move |rhs| $crate::none_of!({$($lh_sides),+}.$method $args $operator rhs)
+    };
+
+    ({$($lh_sides:expr),+ $(,)?}.$method:ident $args:tt $operator:tt *_) => {
+        move |rhs: &_| $crate::none_of!({$($lh_sides),+}.$method $args $operator *rhs)
+    };
+
+    // variants that compare homogeneous sets of numbers using SIMD
+    (simd {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $crate::__simd_compare!($crate::simd::none_of_simd(&[$(map_func($lh_sides)),+]), $operator, $rhs)
+        }
+    };
+
+    (simd {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__simd_compare!($crate::simd::none_of_simd(&[$($lh_sides),+]), $operator, $rhs)
+        }
+    };
+
+    // eager variants that evaluate all comparisons
+    (eager {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::none_of!(eager {$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    (eager {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            !($( (map_func($lh_sides) $operator $rhs) )|+)
+        }
+    };
+
+    (eager {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            !($( ($lh_sides $operator $rhs) )|+)
+        }
+    };
+
+    // variants that pair the values with their indices or with the values of a second set
+    ( {$($lh_sides:expr),+ $(,)?}.enumerate() $($rest:tt)+ ) => {
+        $crate::__enumerate!(none_of, {$($lh_sides),+} $($rest)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.zip({$($other_sides:expr),+ $(,)?}) $($rest:tt)+ ) => {
+        $crate::__zip!(none_of, [], [$($lh_sides),+], [$($other_sides),+], $($rest)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::none_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy_kleene($($func:tt)+) ) => {
+        {
+            let map_func = $($func)+;
+            $crate::__kleene_quantify!(true => false, true; $(map_func($lh_sides)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).kleene() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $crate::__kleene_quantify!(true => false, true; $($crate::__strict_compare!(map_func($lh_sides), $operator, $rhs)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).total() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( !($crate::total::Total(&map_func($lh_sides)) $operator $crate::total::Total(&$rhs)) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+).strict() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'strict: {
+                $(
+                    match $crate::__strict_compare!(map_func($lh_sides), $operator, $rhs).map(::core::ops::Not::not) {
+                        ::core::option::Option::Some(true) => {}
+                        decided => break 'strict decided,
+                    }
+                )+
+                ::core::option::Option::Some(true)
+            }
+        }
+    };
+
+    // variants with a chain of transformations and filters
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) . $($chain:tt)+ ) => {
+        $crate::__pipeline_collect!(none, [$($lh_sides),+], [], .map($($func)+) . $($chain)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.filter($($func:tt)+) $($chain:tt)+ ) => {
+        $crate::__pipeline_collect!(none, [$($lh_sides),+], [], .filter($($func)+) $($chain)+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( !(map_func($lh_sides) $operator $rhs) )&&+
+        }
+    };
+
+    // variants that compare using a custom ordering or key
+    ( {$first:expr $(, $rest:expr)* $(,)?}.by(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let cmp_func = $crate::by::comparator(first, |$($closure)+);
+            !($crate::__ordering_satisfies!(cmp_func(first, &$rhs), $operator)) $( && !($crate::__ordering_satisfies!(cmp_func(&$rest, &$rhs), $operator)) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.by($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let cmp_func = $($func)+;
+            $( !($crate::__ordering_satisfies!(cmp_func(&$lh_sides, &$rhs), $operator)) )&&+
+        }
+    };
+
+    ( {$first:expr $(, $rest:expr)* $(,)?}.by_key(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let key_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            !(key_func(first) $operator key_func(&$rhs)) $( && !(key_func(&$rest) $operator key_func(&$rhs)) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.by_key($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let key_func = $($func)+;
+            $( !(key_func(&$lh_sides) $operator key_func(&$rhs)) )&&+
+        }
+    };
+
+    // variants that pass references to the values to the transformation
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy_ref($($func:tt)+) ) => {
+        $crate::none_of!({$($lh_sides),+}.map_ref($($func)+)==true)
+    };
+
+    ( {$first:expr $(, $rest:expr)* $(,)?}.map_ref(|$($closure:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let first = &$first;
+            let map_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            !(map_func(first) $operator $rhs) $( && !(map_func(&$rest) $operator $rhs) )*
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map_ref($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( !(map_func(&$lh_sides) $operator $rhs) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}?.satisfy($($func:tt)+) ) => {
+        $crate::none_of!({$($lh_sides),+}?.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}?.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'lifted: {
+                $(
+                    match $crate::lift::Lift::lift($lh_sides) {
+                        ::core::result::Result::Ok(value) => if (map_func(value) $operator $rhs) {
+                            break 'lifted $crate::lift::LiftOutput::from_bool(false);
+                        },
+                        ::core::result::Result::Err(output) => break 'lifted output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}? $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            'lifted: {
+                $(
+                    match $crate::lift::Lift::lift($lh_sides) {
+                        ::core::result::Result::Ok(value) => if (value $operator $rhs) {
+                            break 'lifted $crate::lift::LiftOutput::from_bool(false);
+                        },
+                        ::core::result::Result::Err(output) => break 'lifted output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr).satisfy($($func:tt)+) ) => {
+        $crate::none_of!({$($lh_sides),+}.lift_or($default).map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr).map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( !(match $crate::lift::Lift::lift($lh_sides) {
+                ::core::result::Result::Ok(value) => map_func(value) $operator $rhs,
+                ::core::result::Result::Err(_) => $default,
+            }) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.lift_or($default:expr) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( !(match $crate::lift::Lift::lift($lh_sides) {
+                ::core::result::Result::Ok(value) => value $operator $rhs,
+                ::core::result::Result::Err(_) => $default,
+            }) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.kleene() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__kleene_quantify!(true => false, true; $($crate::__strict_compare!($lh_sides, $operator, $rhs)),+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.kleene() ) => {
+        $crate::__kleene_quantify!(true => false, true; $($lh_sides),+)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.total() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( !($crate::total::Total(&$lh_sides) $operator $crate::total::Total(&$rhs)) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.strict() $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            'strict: {
+                $(
+                    match $crate::__strict_compare!($lh_sides, $operator, $rhs).map(::core::ops::Not::not) {
+                        ::core::option::Option::Some(true) => {}
+                        decided => break 'strict decided,
+                    }
+                )+
+                ::core::option::Option::Some(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr)=> {
+        {
+            $crate::__check_operator!($operator);
+            $( !($lh_sides $operator $rhs) )&&+
+        }
+    };
+
+    // membership tests against sets of literals
+    ($($membership:tt)+) => {
+        $crate::__membership!([!] == [] $($membership)+)
+    };
+}
+
+/// The fallible counterpart of [any_of](crate::any_of) for transformations that can fail.
+///
+/// # Usage
+/// The syntax is the same as for `any_of`, but the invokable given to `.map(...)` or `.satisfy(...)`
+/// returns a `Result<T,E>` or an `Option<T>`. The transformation is applied to the values lazily from left to right.
+/// The first error (or `None`) is returned immediately and no further values are transformed.
+/// Otherwise the macro evaluates to `Ok(result)` (or `Some(result)`), where `result` is the result of the comparison
+/// as for `any_of`. Without a transformation, the values themselves must be `Result`s (or `Option`s), which makes
+/// `try_any_of!({...} operator rhs)` the same as `any_of!({...}? operator rhs)`.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::try_any_of;
+/// # use std::num::ParseIntError;
+/// let parse = |s: &str| s.parse::<i32>();
+/// // the following assertions hold
+/// assert_eq!(try_any_of!({"1","5"}.map(parse) > 3), Ok(true));
+/// assert_eq!(try_any_of!({"1","2"}.map(parse) > 3), Ok(false));
+/// assert!(try_any_of!({"1","five","5"}.map(parse) > 3).is_err());
+/// // the evaluation stops before the error
+/// assert_eq!(try_any_of!({"5","five"}.map(parse) > 3), Ok(true));
+///
+/// // this also works with the question mark operator
+/// fn some_greater_than_three(s1: &str, s2: &str) -> Result<bool,ParseIntError> {
+///     Ok(try_any_of!({s1,s2}.map(|s:&str|s.parse::<i32>()) > 3)?)
+/// }
+/// assert_eq!(some_greater_than_three("1","4"), Ok(true));
+///
+/// // and with Options
+/// let lookup = |key| [1,2,3].get(key).copied();
+/// assert_eq!(try_any_of!({0,1}.satisfy(|key|lookup(key).map(|value|value>1))), Some(true));
+/// assert_eq!(try_any_of!({0,10}.map(lookup) > 1), None);
+/// ```
+#[macro_export]
+macro_rules! try_any_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::try_any_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'fallible: {
+                $(
+                    match $crate::lift::Lift::lift(map_func($lh_sides)) {
+                        ::core::result::Result::Ok(value) => if (value $operator $rhs) {
+                            break 'fallible $crate::lift::LiftOutput::from_bool(true);
+                        },
+                        ::core::result::Result::Err(output) => break 'fallible output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(false)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        $crate::any_of!({$($lh_sides),+}? $operator $rhs)
+    };
+}
+
+/// The fallible counterpart of [all_of](crate::all_of) for transformations that can fail.
+///
+/// # Usage
+/// The usage is analogous to the [try_any_of](crate::try_any_of) macro and is documented in more detail there.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::try_all_of;
+/// let parse = |s: &str| s.parse::<i32>();
+/// // the following assertions hold
+/// assert_eq!(try_all_of!({"1","5"}.map(parse) > 0), Ok(true));
+/// assert_eq!(try_all_of!({"1","5"}.map(parse) > 3), Ok(false));
+/// assert!(try_all_of!({"1","five"}.map(parse) > 0).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_all_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::try_all_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'fallible: {
+                $(
+                    match $crate::lift::Lift::lift(map_func($lh_sides)) {
+                        ::core::result::Result::Ok(value) => if !(value $operator $rhs) {
+                            break 'fallible $crate::lift::LiftOutput::from_bool(false);
+                        },
+                        ::core::result::Result::Err(output) => break 'fallible output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        $crate::all_of!({$($lh_sides),+}? $operator $rhs)
+    };
+}
+
+/// The fallible counterpart of [none_of](crate::none_of) for transformations that can fail.
+///
+/// # Usage
+/// The usage is analogous to the [try_any_of](crate::try_any_of) macro and is documented in more detail there.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::try_none_of;
+/// let parse = |s: &str| s.parse::<i32>();
+/// // the following assertions hold
+/// assert_eq!(try_none_of!({"1","5"}.map(parse) > 5), Ok(true));
+/// assert_eq!(try_none_of!({"1","5"}.map(parse) > 3), Ok(false));
+/// assert!(try_none_of!({"1","five"}.map(parse) > 5).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_none_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::try_none_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            'fallible: {
+                $(
+                    match $crate::lift::Lift::lift(map_func($lh_sides)) {
+                        ::core::result::Result::Ok(value) => if (value $operator $rhs) {
+                            break 'fallible $crate::lift::LiftOutput::from_bool(false);
+                        },
+                        ::core::result::Result::Err(output) => break 'fallible output,
+                    }
+                )+
+                $crate::lift::LiftOutput::from_bool(true)
+            }
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        $crate::none_of!({$($lh_sides),+}? $operator $rhs)
+    };
+}
+
+/// The asynchronous counterpart of [any_of](crate::any_of), where the values in the set are futures.
+///
+/// # Usage
+/// The syntax is the same as for `any_of`, including `.map(...)` and `.satisfy(...)`, but the expressions in the set
+/// must be futures. The macro must be invoked in an asynchronous context, i.e. in an `async` function or block,
+/// because the futures are `.await`ed one at a time from left to right. The transformation and the comparison are
+/// applied to the outputs of the futures.
+///
+/// # Lazy Evaluation
+/// Just like for `any_of`, the evaluation stops as soon as the result is known. The expressions of the remaining
+/// futures are not even evaluated, so the remaining futures are never created or polled.
+/// The macro does not depend on any runtime, so it can be used with any executor.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::any_of_async;
+/// async fn lookup(key: usize) -> Option<&'static str> {
+///     ["zero", "one", "two"].get(key).copied()
+/// }
+///
+/// async fn is_cached(key: usize) -> bool {
+///     // if the first lookup is a hit, the second lookup never happens
+///     any_of_async!({lookup(key),lookup(key+1)}.satisfy(|hit:Option<_>|hit.is_some()))
+/// }
+///
+/// async fn has_one(key: usize) -> bool {
+///     any_of_async!({lookup(key),lookup(key+1)} == Some("one"))
+/// }
+/// ```
+#[macro_export]
+macro_rules! any_of_async {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::any_of_async!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func($lh_sides.await) $operator $rhs) )||+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( ($lh_sides.await $operator $rhs) )||+
+        }
+    };
+}
+
+/// The asynchronous counterpart of [all_of](crate::all_of), where the values in the set are futures.
+///
+/// # Usage
+/// The usage is analogous to the [any_of_async](crate::any_of_async) macro and is documented in more detail there.
+/// The futures are `.await`ed one at a time from left to right until the result is known.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::all_of_async;
+/// async fn length(s: &str) -> usize {
+///     s.len()
+/// }
+///
+/// async fn all_short(a: &str, b: &str) -> bool {
+///     all_of_async!({length(a),length(b)} < 10)
+/// }
+/// ```
+#[macro_export]
+macro_rules! all_of_async {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::all_of_async!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( (map_func($lh_sides.await) $operator $rhs) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( ($lh_sides.await $operator $rhs) )&&+
+        }
+    };
+}
+
+/// The asynchronous counterpart of [none_of](crate::none_of), where the values in the set are futures.
+///
+/// # Usage
+/// The usage is analogous to the [any_of_async](crate::any_of_async) macro and is documented in more detail there.
+/// The futures are `.await`ed one at a time from left to right until the result is known.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::none_of_async;
+/// async fn length(s: &str) -> usize {
+///     s.len()
+/// }
+///
+/// async fn none_empty(a: &str, b: &str) -> bool {
+///     none_of_async!({length(a),length(b)}.map(|len|len==0) == true)
+/// }
+/// ```
+#[macro_export]
+macro_rules! none_of_async {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::none_of_async!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( !(map_func($lh_sides.await) $operator $rhs) )&&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( !($lh_sides.await $operator $rhs) )&&+
+        }
+    };
+}
+
+/// The concurrent counterpart of [any_of_async](crate::any_of_async), which polls all futures in the set together.
+///
+/// # Usage
+/// The syntax is the same as for `any_of_async`, including `.map(...)` and `.satisfy(...)`, and the macro must
+/// also be invoked in an asynchronous context. Instead of awaiting the futures one at a time, the macro polls all of them
+/// concurrently. It resolves to `true` as soon as the comparison is `true` for one of the outputs and
+/// to `false` once the comparisons for all outputs were `false`. This is useful when the futures are independent and
+/// awaiting them one after the other would add up their latencies.
+///
+/// # Evaluation Order
+/// Each value is awaited, transformed and compared in its own `async` block. On every poll, the futures that have not yet resolved
+/// are polled from left to right. Once the result is known, the remaining futures are not polled anymore and are dropped
+/// together with the expression. The order in which the comparisons are performed depends on when the futures
+/// resolve, so the left to right evaluation guarantee of the other macros does not apply. The macro
+/// only relies on `core::future`, so it can be used with any executor.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::any_of_concurrent;
+/// async fn lookup(server: usize, key: usize) -> Option<usize> {
+///     // pretend this is a network call
+///     (server == key).then(|| key)
+/// }
+///
+/// async fn is_known(key: usize) -> bool {
+///     // all servers are asked at the same time
+///     any_of_concurrent!({lookup(1,key),lookup(2,key),lookup(3,key)}.satisfy(|hit:Option<_>|hit.is_some()))
+/// }
+/// ```
+#[macro_export]
+macro_rules! any_of_concurrent {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::any_of_concurrent!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            let decided = $crate::concurrent::race(true, [$(
+                ::core::pin::pin!(async { map_func($lh_sides.await) $operator $rhs })
+            ),+]).await;
+            decided
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let decided = $crate::concurrent::race(true, [$(
+                ::core::pin::pin!(async { $lh_sides.await $operator $rhs })
+            ),+]).await;
+            decided
+        }
+    };
+}
+
+/// The concurrent counterpart of [all_of_async](crate::all_of_async), which polls all futures in the set together.
+///
+/// # Usage
+/// The usage is analogous to the [any_of_concurrent](crate::any_of_concurrent) macro and is documented in more detail there.
+/// The macro resolves to `false` as soon as the comparison fails for one of the outputs and to `true` once the comparisons for
+/// all outputs were `true`.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::all_of_concurrent;
+/// async fn health_check(server: usize) -> u32 {
+///     // pretend this is a network call that returns a status code
+///     200
+/// }
+///
+/// async fn all_healthy() -> bool {
+///     all_of_concurrent!({health_check(1),health_check(2),health_check(3)} == 200)
+/// }
+/// ```
+#[macro_export]
+macro_rules! all_of_concurrent {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::all_of_concurrent!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            let decided = $crate::concurrent::race(false, [$(
+                ::core::pin::pin!(async { map_func($lh_sides.await) $operator $rhs })
+            ),+]).await;
+            !decided
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let decided = $crate::concurrent::race(false, [$(
+                ::core::pin::pin!(async { $lh_sides.await $operator $rhs })
+            ),+]).await;
+            !decided
+        }
+    };
+}
+
+/// The concurrent counterpart of [none_of_async](crate::none_of_async), which polls all futures in the set together.
+///
+/// # Usage
+/// The usage is analogous to the [any_of_concurrent](crate::any_of_concurrent) macro and is documented in more detail there.
+/// The macro resolves to `false` as soon as the comparison holds for one of the outputs and to `true` once the comparisons for
+/// all outputs were `false`.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::none_of_concurrent;
+/// async fn error_count(server: usize) -> usize {
+///     // pretend this is a network call
+///     0
+/// }
+///
+/// async fn no_errors() -> bool {
+///     none_of_concurrent!({error_count(1),error_count(2)} > 0)
+/// }
+/// ```
+#[macro_export]
+macro_rules! none_of_concurrent {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::none_of_concurrent!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            let decided = $crate::concurrent::race(true, [$(
+                ::core::pin::pin!(async { map_func($lh_sides.await) $operator $rhs })
+            ),+]).await;
+            !decided
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let decided = $crate::concurrent::race(true, [$(
+                ::core::pin::pin!(async { $lh_sides.await $operator $rhs })
+            ),+]).await;
+            !decided
+        }
+    };
+}
+
+#[cfg(feature = "rayon")]
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro splits the comparisons of a parallel quantifier into two halves by putting every other comparison into
+/// the second half, evaluates the halves in parallel using the given [Race](crate::parallel::Race) and does the same
+/// for each half until the comparisons are evaluated one by one.
+macro_rules! __par_join {
+    (@split $race:ident, [$($lhs:tt)*] [$($rhs:tt)*] $first:tt $second:tt $($rest:tt)*) => {
+        $crate::__par_join!(@split $race, [$($lhs)* $first] [$($rhs)* $second] $($rest)*)
+    };
+    (@split $race:ident, [$($lhs:tt)*] [$($rhs:tt)*] $last:tt) => {
+        $crate::__par_join!(@split $race, [$($lhs)* $last] [$($rhs)*])
+    };
+    (@split $race:ident, [$($lhs:tt)+] [$($rhs:tt)+]) => {
+        $race.join(|| $crate::__par_join!($race, $($lhs)+), || $crate::__par_join!($race, $($rhs)+))
+    };
+    ($race:ident, ($($comparison:tt)+)) => {
+        $race.compare(|| $($comparison)+)
+    };
+    ($race:ident, $($comparisons:tt)+) => {
+        $crate::__par_join!(@split $race, [] [] $($comparisons)+)
+    };
+}
+
+/// The parallel counterpart of [any_of](crate::any_of), which evaluates the comparisons on multiple threads.
+///
+/// This macro is only available with the `rayon` feature.
+///
+/// # Usage
+/// The syntax is the same as for `any_of`, including `.map(...)` and `.satisfy(...)`. Each value is evaluated,
+/// transformed and compared in its own closure and the closures are run in parallel on the
+/// [rayon](https://docs.rs/rayon) thread pool. That pays off if evaluating the values or the transformation is expensive,
+/// e.g. for hashing, simulations, or validating files. For cheap comparisons the overhead of distributing the work
+/// outweighs the benefits, so prefer `any_of` for those.
+///
+/// The right hand side and the transformation are evaluated once up front and shared between threads by reference, so they must be
+/// `Sync`. Each value is computed and compared on the thread that runs its closure, so the expressions and the variables they move
+/// must be `Send`. Owned values like a `String` are moved into their closure, so they don't have to be `Copy`.
+///
+/// For values that are only known at runtime, use the functions [any_of](crate::parallel::any_of), [all_of](crate::parallel::all_of)
+/// and [none_of](crate::parallel::none_of) of the `parallel` module, which accept anything that rayon can iterate over in parallel.
+///
+/// # Evaluation Order
+/// **The left to right evaluation guarantee of the other macros does not apply here**. The values are evaluated in an
+/// unspecified order, possibly at the same time. Once a comparison is `true`, no further comparisons are started, but comparisons that were
+/// already started on other threads run to completion. So it is not specified which expressions are evaluated
+/// (other than the ones needed to decide the result), and side effects in the expressions should be avoided.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::par_any_of;
+/// fn expensive_hash(x: u64) -> u64 {
+///     (0..1000).fold(x, |hash, i| hash.wrapping_mul(31).wrapping_add(i))
+/// }
+/// let target = expensive_hash(3);
+/// // the following assertions hold
+/// assert!(par_any_of!({1,2,3,4}.map(expensive_hash) == target));
+/// assert!(par_any_of!({expensive_hash(1), expensive_hash(3)} == target));
+/// assert!(!par_any_of!({5,6}.satisfy(|x|expensive_hash(x) == target)));
+/// ```
+#[cfg(feature = "rayon")]
+#[macro_export]
+macro_rules! par_any_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::par_any_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = &$($func)+;
+            let rhs = &$rhs;
+            let race = $crate::parallel::Race::new(true);
+            $crate::__par_join!(race, $((map_func($lh_sides) $operator *rhs))+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let rhs = &$rhs;
+            let race = $crate::parallel::Race::new(true);
+            $crate::__par_join!(race, $(($lh_sides $operator *rhs))+)
+        }
+    };
+}
+
+/// The parallel counterpart of [all_of](crate::all_of), which evaluates the comparisons on multiple threads.
+///
+/// This macro is only available with the `rayon` feature.
+///
+/// # Usage
+/// The usage is analogous to the [par_any_of](crate::par_any_of) macro and is documented in more detail there.
+/// In particular, **the left to right evaluation guarantee does not apply**. Once a comparison is `false`, no
+/// further comparisons are started.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::par_all_of;
+/// fn is_valid(checksum: u64) -> bool {
+///     // pretend this is expensive
+///     checksum % 2 == 0
+/// }
+/// // the following assertions hold
+/// assert!(par_all_of!({2,4,6}.satisfy(is_valid)));
+/// assert!(!par_all_of!({2,3,6}.map(is_valid) == true));
+/// ```
+#[cfg(feature = "rayon")]
+#[macro_export]
+macro_rules! par_all_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::par_all_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = &$($func)+;
+            let rhs = &$rhs;
+            let race = $crate::parallel::Race::new(false);
+            $crate::__par_join!(race, $((map_func($lh_sides) $operator *rhs))+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let rhs = &$rhs;
+            let race = $crate::parallel::Race::new(false);
+            $crate::__par_join!(race, $(($lh_sides $operator *rhs))+)
+        }
+    };
+}
+
+/// The parallel counterpart of [none_of](crate::none_of), which evaluates the comparisons on multiple threads.
+///
+/// This macro is only available with the `rayon` feature.
+///
+/// # Usage
+/// The usage is analogous to the [par_any_of](crate::par_any_of) macro and is documented in more detail there.
+/// In particular, **the left to right evaluation guarantee does not apply**. Once a comparison is `true`, no
+/// further comparisons are started.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::par_none_of;
+/// fn simulate(seed: u64) -> u64 {
+///     // pretend this is expensive
+///     seed * 3
+/// }
+/// // the following assertions hold
+/// assert!(par_none_of!({1,2,3}.map(simulate) > 10));
+/// assert!(!par_none_of!({simulate(1), simulate(4)} > 10));
+/// ```
+#[cfg(feature = "rayon")]
+#[macro_export]
+macro_rules! par_none_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        $crate::par_none_of!({$($lh_sides),+}.map($($func)+)==true)
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = &$($func)+;
+            let rhs = &$rhs;
+            let race = $crate::parallel::Race::new(true);
+            !$crate::__par_join!(race, $((map_func($lh_sides) $operator *rhs))+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let rhs = &$rhs;
+            let race = $crate::parallel::Race::new(true);
+            !$crate::__par_join!(race, $(($lh_sides $operator *rhs))+)
+        }
+    };
+}
+
+/// The constant time counterpart of [any_of](crate::any_of) for comparing secret data.
+///
+/// This macro is only available with the `constant-time` feature.
+///
+/// # Usage
+/// The syntax is the same as for `any_of`, including `.map(...)`, but the comparisons are performed using the traits
+/// of the [subtle](https://docs.rs/subtle) crate and the macro evaluates to a [Choice](crate::constant_time::subtle::Choice)
+/// instead of a `bool`. The operators `==` and `!=` use `ConstantTimeEq`, while `<`, `>`, `<=` and `>=` use `ConstantTimeGreater`
+/// and `ConstantTimeLess`, which `subtle` implements for the unsigned integers. The predicate given to `.satisfy(...)`
+/// must return a `Choice`. Since the comparisons are method calls, integer literals in the set need an explicit type suffix.
+///
+/// # Constant Time Evaluation
+/// In contrast to the other macros, *all* values are compared, and the results are combined using the non
+/// short-circuiting operator `|` on `Choice`. So the expansion contains no branches that depend on the
+/// results of the comparisons and does not leak through its timing which of the values matched. Note that this only holds if
+/// the expressions and transformations themselves run in constant time.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::ct_any_of;
+/// let tag = [0x17_u8, 0x42, 0x99];
+/// let candidate_key = [0x17_u8, 0x42, 0x99];
+/// let other_key = [0x00_u8, 0x00, 0x00];
+/// // the following assertions hold
+/// assert!(bool::from(ct_any_of!({other_key,candidate_key} == tag)));
+/// assert!(!bool::from(ct_any_of!({other_key} == tag)));
+/// assert!(bool::from(ct_any_of!({3_u32,7_u32} > 5)));
+/// ```
+#[cfg(feature = "constant-time")]
+#[macro_export]
+macro_rules! ct_any_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        {
+            let map_func = $($func)+;
+            $( map_func($lh_sides) )|+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( $crate::__ct_compare!(map_func($lh_sides), $operator, $rhs) )|+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( $crate::__ct_compare!($lh_sides, $operator, $rhs) )|+
+        }
+    };
+}
+
+/// The constant time counterpart of [all_of](crate::all_of) for comparing secret data.
+///
+/// This macro is only available with the `constant-time` feature.
+///
+/// # Usage
+/// The usage is analogous to the [ct_any_of](crate::ct_any_of) macro and is documented in more detail there.
+/// The results of all comparisons are combined using the non short-circuiting operator `&` on `Choice`.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::ct_all_of;
+/// let (a, b) = (7_u64, 9_u64);
+/// // the following assertions hold
+/// assert!(bool::from(ct_all_of!({a,b} >= 7)));
+/// assert!(!bool::from(ct_all_of!({a,b}.map(|x|x*2) == 14)));
+/// ```
+#[cfg(feature = "constant-time")]
+#[macro_export]
+macro_rules! ct_all_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        {
+            let map_func = $($func)+;
+            $( map_func($lh_sides) )&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            $( $crate::__ct_compare!(map_func($lh_sides), $operator, $rhs) )&+
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            $( $crate::__ct_compare!($lh_sides, $operator, $rhs) )&+
+        }
+    };
+}
+
+/// The constant time counterpart of [none_of](crate::none_of) for comparing secret data.
+///
+/// This macro is only available with the `constant-time` feature.
+///
+/// # Usage
+/// The usage is analogous to the [ct_any_of](crate::ct_any_of) macro and is documented in more detail there.
+/// The results of all comparisons are combined using the non short-circuiting operator `|` on `Choice`
+/// and then negated.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::ct_none_of;
+/// let revoked = [[1_u8; 4], [2_u8; 4]];
+/// let token = [3_u8; 4];
+/// // the following assertion holds
+/// assert!(bool::from(ct_none_of!({revoked[0],revoked[1]} == token)));
+/// ```
+#[cfg(feature = "constant-time")]
+#[macro_export]
+macro_rules! ct_none_of {
+    ( {$($lh_sides:expr),+ $(,)?}.satisfy($($func:tt)+) ) => {
+        {
+            let map_func = $($func)+;
+            !($( map_func($lh_sides) )|+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+) $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            let map_func = $($func)+;
+            !($( $crate::__ct_compare!(map_func($lh_sides), $operator, $rhs) )|+)
+        }
+    };
+
+    ( {$($lh_sides:expr),+ $(,)?} $operator:tt $rhs:expr) => {
+        {
+            $crate::__check_operator!($operator);
+            !($( $crate::__ct_compare!($lh_sides, $operator, $rhs) )|+)
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Assert at compile time that a quantifier holds for every single element of a set, so that
+/// a failing assertion can name the offending element. The condition is passed as a single token
+/// tree, because it is repeated for every element.
+macro_rules! __const_assert_each {
+    ($quantifier:ident, $message:literal, $condition:tt, $($lh_sides:expr),+) => {
+        const _: () = {
+            $( $crate::__const_assert_element!($quantifier, $message, $lh_sides, $condition); )+
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Assert the quantifier for a single element of a set, see `__const_assert_each`.
+macro_rules! __const_assert_element {
+    ($quantifier:ident, $message:literal, $lh_side:expr, ($($condition:tt)+)) => {
+        if !$crate::$quantifier!({$lh_side} $($condition)+) {
+            ::core::panic!(::core::concat!(
+                "const_assert_", ::core::stringify!($quantifier), " failed: `",
+                ::core::stringify!($lh_side), "` ", $message, " `", ::core::stringify!($($condition)+), "`"
+            ));
+        }
+    };
+}
+
+/// Assert at compile time that [all_of](crate::all_of) holds, failing the build with a message
+/// naming the first element that violates the condition.
+///
+/// # Usage
+/// The syntax is the same as for `all_of`, but all forms that can be evaluated in const contexts are supported
+/// (see the section on const evaluation in the documentation of [any_of](crate::any_of)). The assertion expands
+/// to an anonymous constant item, so it can be used at module level as well as inside functions.
+/// The values must be constants.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::const_assert_all_of;
+/// const BUFFER_SIZE: usize = 256;
+/// const HEADER_SIZE: usize = 16;
+/// const PAYLOAD_SIZE: usize = 128;
+/// const fn is_power_of_two(x: usize) -> bool { x.is_power_of_two() }
+/// const fn double(x: usize) -> usize { 2 * x }
+///
+/// const_assert_all_of!({HEADER_SIZE, PAYLOAD_SIZE} <= BUFFER_SIZE);
+/// const_assert_all_of!({HEADER_SIZE, PAYLOAD_SIZE, BUFFER_SIZE}.satisfy(is_power_of_two));
+/// const_assert_all_of!({HEADER_SIZE, PAYLOAD_SIZE}.map(double) <= BUFFER_SIZE);
+/// ```
+///
+/// A failing assertion names the offending element, e.g. this fails to compile with the message
+/// ``const_assert_all_of failed: `PAYLOAD_SIZE` does not satisfy `< 100` ``:
+/// ```compile_fail
+/// # use fluent_comparisons_macros::const_assert_all_of;
+/// # const HEADER_SIZE: usize = 16;
+/// # const PAYLOAD_SIZE: usize = 128;
+/// const_assert_all_of!({HEADER_SIZE, PAYLOAD_SIZE} < 100);
+/// ```
+#[macro_export]
+macro_rules! const_assert_all_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($condition:tt)+ ) => {
+        $crate::__const_assert_each!(all_of, "does not satisfy", ($($condition)+), $($lh_sides),+);
+    };
+}
+
+/// Assert at compile time that [any_of](crate::any_of) holds, failing the build with a message
+/// naming the elements of the set.
+///
+/// # Usage
+/// The usage is analogous to the [const_assert_all_of](crate::const_assert_all_of) macro and is documented in more detail there.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::const_assert_any_of;
+/// const PRIMARY_PORT: u16 = 8080;
+/// const FALLBACK_PORT: u16 = 443;
+///
+/// const_assert_any_of!({PRIMARY_PORT, FALLBACK_PORT} < 1024);
+/// ```
+///
+/// ```compile_fail
+/// # use fluent_comparisons_macros::const_assert_any_of;
+/// # const PRIMARY_PORT: u16 = 8080;
+/// # const FALLBACK_PORT: u16 = 443;
+/// // fails with: const_assert_any_of failed: none of `PRIMARY_PORT, FALLBACK_PORT` satisfies `== 80`
+/// const_assert_any_of!({PRIMARY_PORT, FALLBACK_PORT} == 80);
+/// ```
+#[macro_export]
+macro_rules! const_assert_any_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($condition:tt)+ ) => {
+        const _: () = {
+            if !$crate::any_of!({$($lh_sides),+} $($condition)+) {
+                ::core::panic!(::core::concat!(
+                    "const_assert_any_of failed: none of `", ::core::stringify!($($lh_sides),+),
+                    "` satisfies `", ::core::stringify!($($condition)+), "`"
+                ));
+            }
+        };
+    };
+}
+
+/// Assert at compile time that [none_of](crate::none_of) holds, failing the build with a message
+/// naming the first element that satisfies the condition.
+///
+/// # Usage
+/// The usage is analogous to the [const_assert_all_of](crate::const_assert_all_of) macro and is documented in more detail there.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::const_assert_none_of;
+/// const TIMEOUTS_MS: [u64; 3] = [100, 250, 1000];
+///
+/// const_assert_none_of!({TIMEOUTS_MS[0], TIMEOUTS_MS[1], TIMEOUTS_MS[2]} == 0);
+/// ```
+#[macro_export]
+macro_rules! const_assert_none_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($condition:tt)+ ) => {
+        $crate::__const_assert_each!(none_of, "satisfies", ($($condition)+), $($lh_sides),+);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro decides a relation between the values of a set, where each value is evaluated once and bound to a
+/// variable, because it takes part in more than one comparison. With `.by_key(...)`, the keys of the values are bound instead.
+/// The relation is either `(adjacent $operator)`, which compares each value with the value before it using the operator,
+/// or `(distinct)`, which compares each value with all values before it using `!=`.
+macro_rules! __pairwise {
+    ($relation:tt, {$first:expr $(, $rest:expr)* $(,)?}.by_key(|$($closure:tt)+)) => {
+        {
+            let first = &$first;
+            let key_func = $crate::borrow::higher_ranked(first, |$($closure)+);
+            let value = key_func(first);
+            $crate::__pairwise_compare!($relation, (key_func), [value]; $($rest),*)
+        }
+    };
+    ($relation:tt, {$first:expr $(, $rest:expr)* $(,)?}.by_key($($func:tt)+)) => {
+        {
+            let key_func = $($func)+;
+            let value = key_func(&$first);
+            $crate::__pairwise_compare!($relation, (key_func), [value]; $($rest),*)
+        }
+    };
+    ($relation:tt, {$first:expr $(, $rest:expr)* $(,)?}) => {
+        {
+            let value = &$first;
+            $crate::__pairwise_compare!($relation, (), [value]; $($rest),*)
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro evaluates the next value of a set and compares it to the values before it, which were bound to the identifiers
+/// in `[$($previous)*]`. The evaluation stops at the first comparison that does not hold.
+macro_rules! __pairwise_compare {
+    ((adjacent $operator:tt), $key:tt, [$previous:ident]; $next:expr $(, $rest:expr)*) => {
+        {
+            let value = $crate::__pairwise_value!($key, $next);
+            ($previous $operator value) && $crate::__pairwise_compare!((adjacent $operator), $key, [value]; $($rest),*)
+        }
+    };
+    ((distinct), $key:tt, [$($previous:ident)*]; $next:expr $(, $rest:expr)*) => {
+        {
+            let value = $crate::__pairwise_value!($key, $next);
+            $( ($previous != value) && )* $crate::__pairwise_compare!((distinct), $key, [$($previous)* value]; $($rest),*)
+        }
+    };
+    ($relation:tt, $key:tt, $previous:tt;) => {
+        true
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro evaluates a value of a set for the pairwise relations. Without a key function `()`,
+/// it evaluates to a reference to the value. With a key function `(key_func)`, it evaluates to the key of the value.
+macro_rules! __pairwise_value {
+    ((), $value:expr) => {
+        &$value
+    };
+    (($key_func:ident), $value:expr) => {
+        $key_func(&$value)
+    };
+}
+
+/// Decide whether all values in a set are equal to each other.
+///
+/// # Usage
+///
+/// In contrast to the quantifiers like [all_of](crate::all_of), this macro compares the values in the set with each other
+/// rather than with a common right hand side. Invoke it as `all_equal!({/*list of expressions*/})`. Since equality is transitive,
+/// it is enough to compare each value with the value before it, so for `n` values this performs at most `n-1` comparisons.
+/// The values are evaluated lazily from left to right and each value is evaluated only once. The evaluation stops at the
+/// first value that is not equal to the value before it. A set with a single value is always equal.
+///
+/// The values need not be of the same type, but each value must implement `PartialEq` with the value after it.
+/// This requires no allocation and no `Hash` implementation.
+///
+/// To compare the values by a key, append `.by_key(key)`, which compares `key(&value)` for all values
+/// like [slice::sort_by_key]. Then the key is computed once per value.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::all_equal;
+/// let (a, b, c) = ("one", "two", "six");
+/// // the following assertions hold
+/// assert!(all_equal!({1, 2 - 1, 3 - 2}));
+/// assert!(!all_equal!({a, b, c}));
+/// assert!(all_equal!({a, b, c}.by_key(str::len)));
+/// assert!(all_equal!({a, String::from("one")}));
+/// ```
+#[macro_export]
+macro_rules! all_equal {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__pairwise!((adjacent ==), {$($lh_sides),+} $($modifier)*)
+    };
+}
+
+/// Decide whether all values in a set are distinct, i.e. no two values are equal.
+///
+/// # Usage
+///
+/// The usage is analogous to the [all_equal](crate::all_equal) macro and is documented in more detail there.
+/// Since being distinct is not transitive, every value is compared with all values before it, so for `n` values this
+/// performs at most `n(n-1)/2` comparisons. This is efficient for small sets and requires no allocation and no `Hash`
+/// implementation. The values are evaluated lazily from left to right and each value is evaluated only once.
+/// The evaluation stops at the first value that is equal to a value before it.
+///
+/// The values need not be of the same type, but each value must implement `PartialEq` with all values after it.
+/// With `.by_key(key)`, the values are distinct if their keys are distinct.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::all_distinct;
+/// let (a, b, c) = ("one", "two", "three");
+/// // the following assertions hold
+/// assert!(all_distinct!({1, 2, 3, 4}));
+/// assert!(!all_distinct!({1, 2, 3, 2}));
+/// assert!(all_distinct!({a, b, c}));
+/// assert!(!all_distinct!({a, b, c}.by_key(str::len)));
+/// ```
+#[macro_export]
+macro_rules! all_distinct {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__pairwise!((distinct), {$($lh_sides),+} $($modifier)*)
+    };
+}
+
+/// Decide whether the values in a set are sorted in ascending order, i.e. each value is less than or equal to the next.
+///
+/// # Usage
+///
+/// This is the same as writing `a <= b && b <= c && c <= d` by hand for the set `{a,b,c,d}`, except that each value is evaluated
+/// only once, even though it takes part in two comparisons. The values are evaluated lazily from left to right and the evaluation
+/// stops at the first pair of values that is not in order. Like [slice::is_sorted], this evaluates to `false` if two adjacent values are
+/// unordered with respect to each other, e.g. if one of them is `NaN`. A set with a single value is always sorted.
+///
+/// The values need not be of the same type, but each value must implement `PartialOrd` with the value after it.
+/// To compare the values by a key, append `.by_key(key)` like for [all_equal](crate::all_equal).
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::is_sorted;
+/// let (a, b, c) = ("a", "bb", "ccc");
+/// // the following assertions hold
+/// assert!(is_sorted!({1, 2, 2, 3}));
+/// assert!(!is_sorted!({1, 3, 2}));
+/// assert!(is_sorted!({c, b, a}.by_key(|s| std::cmp::Reverse(s.len()))));
+/// assert!(!is_sorted!({1., f64::NAN}));
+/// ```
+#[macro_export]
+macro_rules! is_sorted {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__pairwise!((adjacent <=), {$($lh_sides),+} $($modifier)*)
+    };
+}
+
+/// Decide whether the values in a set are strictly increasing, i.e. each value is less than the next.
+///
+/// # Usage
+/// The usage is analogous to the [is_sorted](crate::is_sorted) macro and is documented in more detail there.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::is_strictly_increasing;
+/// // the following assertions hold
+/// assert!(is_strictly_increasing!({1, 2, 3}));
+/// assert!(!is_strictly_increasing!({1, 2, 2}));
+/// assert!(is_strictly_increasing!({"a", "bb", "ccc"}.by_key(str::len)));
+/// ```
+#[macro_export]
+macro_rules! is_strictly_increasing {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__pairwise!((adjacent <), {$($lh_sides),+} $($modifier)*)
+    };
+}
+
+/// Decide whether the values in a set are strictly decreasing, i.e. each value is greater than the next.
+///
+/// # Usage
+/// The usage is analogous to the [is_sorted](crate::is_sorted) macro and is documented in more detail there.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::is_strictly_decreasing;
+/// // the following assertions hold
+/// assert!(is_strictly_decreasing!({3, 2, 1}));
+/// assert!(!is_strictly_decreasing!({3, 3, 1}));
+/// assert!(is_strictly_decreasing!({-3_i32, 2, -1}.by_key(|x| x.abs())));
+/// ```
+#[macro_export]
+macro_rules! is_strictly_decreasing {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__pairwise!((adjacent >), {$($lh_sides),+} $($modifier)*)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro splits the tokens of a chained comparison into operands at the comparison operators. The finished operands
+/// are collected in the first bracket, each as a single `{...}` group followed by its operator, and the tokens of the
+/// current operand are collected in the second bracket.
+macro_rules! __cmp_chain_parse {
+    ([$($operands:tt)*] [$($current:tt)+] == $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)* {$($current)+} ==] [] $($rest)*)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] != $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)* {$($current)+} !=] [] $($rest)*)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] <= $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)* {$($current)+} <=] [] $($rest)*)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] >= $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)* {$($current)+} >=] [] $($rest)*)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] < $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)* {$($current)+} <] [] $($rest)*)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] > $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)* {$($current)+} >] [] $($rest)*)
+    };
+    // logical operators and other operators that can't be part of an operand are rejected like in the other macros
+    ([$($operands:tt)*] [$($current:tt)+] && $($rest:tt)*) => {
+        $crate::__check_operator!(&&)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] || $($rest:tt)*) => {
+        $crate::__check_operator!(||)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] => $($rest:tt)*) => {
+        $crate::__check_operator!(=>)
+    };
+    ([$($operands:tt)*] [$($current:tt)+] = $($rest:tt)*) => {
+        $crate::__check_operator!(=)
+    };
+    ([$($operands:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__cmp_chain_parse!([$($operands)*] [$($current)* $next] $($rest)*)
+    };
+    ([$first:tt $($operands:tt)+] [$($current:tt)+]) => {
+        $crate::__cmp_chain_bind!($first, (), [$($operands)+ {$($current)+}])
+    };
+    ([] [$($current:tt)+]) => {
+        ::core::compile_error!("A chained comparison needs at least two operands joined by one of ==, !=, <=, >=, <, >")
+    };
+    ([$($operands:tt)*] []) => {
+        ::core::compile_error!("Expected an operand after the last comparison operator")
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro evaluates the next operand of a chained comparison once and binds it to a variable, or all values of a
+/// quantified operand to one variable each. The `$pending` argument is `()` for the first operand and otherwise
+/// contains the bound operand before it together with the comparison operator between them.
+macro_rules! __cmp_chain_bind {
+    ({all {$($values:expr),+ $(,)?}}, $pending:tt, $rest:tt) => {
+        $crate::__cmp_chain_bind_each!(all, [], [$($values),+], $pending, $rest)
+    };
+    ({any {$($values:expr),+ $(,)?}}, $pending:tt, $rest:tt) => {
+        $crate::__cmp_chain_bind_each!(any, [], [$($values),+], $pending, $rest)
+    };
+    ({none {$($values:expr),+ $(,)?}}, $pending:tt, $rest:tt) => {
+        $crate::__cmp_chain_bind_each!(none, [], [$($values),+], $pending, $rest)
+    };
+    ({$operand:expr}, $pending:tt, $rest:tt) => {
+        {
+            let value = &$operand;
+            $crate::__cmp_chain_continue!((one value), $pending, $rest)
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro binds the values of a quantified operand of a chained comparison to one variable each, from left to right.
+macro_rules! __cmp_chain_bind_each {
+    ($quantifier:ident, [$($bound:ident)*], [$next:expr $(, $values:expr)*], $pending:tt, $rest:tt) => {
+        {
+            let value = &$next;
+            $crate::__cmp_chain_bind_each!($quantifier, [$($bound)* value], [$($values),*], $pending, $rest)
+        }
+    };
+    ($quantifier:ident, [$($bound:ident)+], [], $pending:tt, $rest:tt) => {
+        $crate::__cmp_chain_continue!(($quantifier [$($bound)+]), $pending, $rest)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro compares a freshly bound operand of a chained comparison with the operand before it and continues with
+/// the next operand only if the comparison holds.
+macro_rules! __cmp_chain_continue {
+    ($bound:tt, (), [$operator:tt $next:tt $($rest:tt)*]) => {
+        $crate::__cmp_chain_bind!($next, ($bound $operator), [$($rest)*])
+    };
+    ($bound:tt, ($previous:tt $operator:tt), []) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__cmp_chain_link!($previous $operator $bound)
+        }
+    };
+    ($bound:tt, ($previous:tt $operator:tt), [$next_operator:tt $next:tt $($rest:tt)*]) => {
+        {
+            $crate::__check_operator!($operator);
+            $crate::__cmp_chain_link!($previous $operator $bound)
+        } && $crate::__cmp_chain_bind!($next, ($bound $next_operator), [$($rest)*])
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro compares two bound operands of a chained comparison. A quantified operand on the left hand side binds
+/// before a quantified operand on the right hand side.
+macro_rules! __cmp_chain_link {
+    ((all [$($lhs:ident)+]) $operator:tt $rhs:tt) => {
+        ($($crate::__cmp_chain_link!((one $lhs) $operator $rhs))&&+)
+    };
+    ((any [$($lhs:ident)+]) $operator:tt $rhs:tt) => {
+        ($($crate::__cmp_chain_link!((one $lhs) $operator $rhs))||+)
+    };
+    ((none [$($lhs:ident)+]) $operator:tt $rhs:tt) => {
+        !($($crate::__cmp_chain_link!((one $lhs) $operator $rhs))||+)
+    };
+    ((one $lhs:ident) $operator:tt (all [$($rhs:ident)+])) => {
+        ($(($lhs $operator $rhs))&&+)
+    };
+    ((one $lhs:ident) $operator:tt (any [$($rhs:ident)+])) => {
+        ($(($lhs $operator $rhs))||+)
+    };
+    ((one $lhs:ident) $operator:tt (none [$($rhs:ident)+])) => {
+        !($(($lhs $operator $rhs))||+)
+    };
+    ((one $lhs:ident) $operator:tt (one $rhs:ident)) => {
+        ($lhs $operator $rhs)
+    };
+}
+
+/// Compare a chain of operands like in mathematical notation, e.g. `cmp_chain!(0 <= x < y <= limit)`.
+///
+/// # Usage
+///
+/// Rust does not allow chaining comparison operators like `a < b < c`, so range and ordering checks have to repeat operands, as in
+/// `0 <= x && x < y && y <= limit`. This macro accepts any sequence of operands joined by the comparison operators `==`, `!=`, `<=`,
+/// `>=`, `<` and `>`, and evaluates to `true` if every comparison of an operand with the operand after it holds. The operators can be
+/// mixed freely. Like for [is_sorted](crate::is_sorted), each operand is evaluated at most once, even though it takes part in two
+/// comparisons. The operands are evaluated lazily from left to right, and the evaluation stops at the first comparison that does not hold.
+///
+/// An operand can also be a set of values with a quantifier, written as `all{...}`, `any{...}` or `none{...}`. A comparison with a
+/// quantified operand holds if it holds for all, any or none of the values in the set, so `cmp_chain!(0 <= all{a,b} < limit)`
+/// is the same as `all_of!({a,b} >= 0) && all_of!({a,b} < limit)`, except that `a` and `b` are evaluated only once. All values of
+/// a quantified operand are evaluated together, before it is compared for the first time. If two quantified operands are compared with each
+/// other, the quantifier on the left hand side binds first, i.e. `any{a,b} < all{c,d}` holds if there is a value on the left hand side that is
+/// less than all values on the right hand side.
+///
+/// The operands are separated at every comparison operator that is not nested inside parentheses, brackets or braces. Put operands that contain
+/// `<` or `>` themselves, e.g. in generic arguments like `size_of::<u32>()`, into parentheses. The logical operators `&&` and `||` are not allowed
+/// between operands.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::cmp_chain;
+/// let (x, y, limit) = (3, 5, 10);
+/// // the following assertions hold
+/// assert!(cmp_chain!(0 <= x < y <= limit));
+/// assert!(!cmp_chain!(0 <= y < x <= limit));
+/// assert!(cmp_chain!(x < y == 5 != limit));
+/// assert!(cmp_chain!(0 <= all{x, y} < limit));
+/// assert!(cmp_chain!(x as i64 > -1));
+/// assert!(cmp_chain!(1 <= (std::mem::size_of::<u32>()) <= 8));
+/// ```
+///
+/// Operators other than the comparison operators fail to compile with the same error as for the quantifiers. For logical
+/// operators between the operands, write two chains instead:
+/// ```compile_fail
+/// # use fluent_comparisons_macros::cmp_chain;
+/// # let x = 3;
+/// cmp_chain!(0 <= x && x < 10);
+/// ```
+#[macro_export]
+macro_rules! cmp_chain {
+    ($($tokens:tt)+) => {
+        $crate::__cmp_chain_parse!([] [] $($tokens)+)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// # Internal Macro
+/// This macro evaluates the values of a set into an array and finds the position of an extremum using the given functions of the
+/// [extrema](crate::extrema) module, the first one for plain values and the second one for `.by_key(...)`. It evaluates to a
+/// tuple of the position and the array of values.
+macro_rules! __extremum {
+    (($index_func:ident, $by_key_func:ident), {$($lh_sides:expr),+ $(,)?}.map($($func:tt)+)) => {
+        {
+            let map_func = $($func)+;
+            let values = [$(map_func($lh_sides)),+];
+            ($crate::extrema::$index_func(&values), values)
+        }
+    };
+    (($index_func:ident, $by_key_func:ident), {$($lh_sides:expr),+ $(,)?}.by_key($($func:tt)+)) => {
+        {
+            let values = [$($lh_sides),+];
+            ($crate::extrema::$by_key_func(&values, $($func)+), values)
+        }
+    };
+    (($index_func:ident, $by_key_func:ident), {$($lh_sides:expr),+ $(,)?}) => {
+        {
+            let values = [$($lh_sides),+];
+            ($crate::extrema::$index_func(&values), values)
+        }
+    };
+}
+
+/// Find the minimum of a set of values.
+///
+/// # Usage
+///
+/// Invoke the macro as `min_of!({/*list of expressions*/})`, which is less noisy than `a.min(b).min(c)` and, in contrast to [Ord::min],
+/// also works for values that only implement `PartialOrd`, like floating point numbers. The values are evaluated from left to right into
+/// an array, so this macro is not lazy and, unlike for the quantifiers, the expressions must all have the same type, like the elements
+/// of an array. With `.map(...)`, the transformed values must have the same type instead. The minimum is moved out of the array and the
+/// other values are dropped. Like for [Iterator::min], the first of several equal minimal values is returned.
+///
+/// Values that are unordered with respect to themselves, like `NaN`, are skipped. So the minimum is `NaN` only if all values are `NaN`,
+/// which is the same as for [f64::min]. If two values are unordered with respect to each other, the one that comes first is kept. This is
+/// documented in more detail in the [extrema](crate::extrema) module.
+///
+/// Transformations are applied to the values like for the quantifiers with `.map(...)`, so that `min_of!({a,b}.map(f))` is the
+/// minimum of `f(a)` and `f(b)`. To find the value with the minimal key instead, use `.by_key(key)`, which compares `key(&value)` like
+/// [Iterator::min_by_key]. Then the key is computed once per value.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::min_of;
+/// let (a, b, c) = ("three", "one", "four");
+/// // the following assertions hold
+/// assert_eq!(min_of!({3, 1, 2}), 1);
+/// assert_eq!(min_of!({2.5, f64::NAN, -1.}), -1.);
+/// assert_eq!(min_of!({a, b, c}.map(str::len)), 3);
+/// assert_eq!(min_of!({a, b, c}.by_key(|s| s.len())), "one");
+/// ```
+#[macro_export]
+macro_rules! min_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        {
+            let (index, values) = $crate::__extremum!((argmin, argmin_by_key), {$($lh_sides),+} $($modifier)*);
+            $crate::extrema::take(values, index)
+        }
+    };
+}
+
+/// Find the maximum of a set of values.
+///
+/// # Usage
+/// The usage is analogous to the [min_of](crate::min_of) macro and is documented in more detail there. Like for [Iterator::max],
+/// the last of several equal maximal values is returned. Values that are unordered with respect to themselves, like `NaN`, are skipped
+/// like for [f64::max].
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::max_of;
+/// let (a, b, c) = ("three", "one", "four");
+/// // the following assertions hold
+/// assert_eq!(max_of!({3, 1, 2}), 3);
+/// assert_eq!(max_of!({f64::NAN, 2.5, -1.}), 2.5);
+/// assert!(max_of!({f64::NAN, f64::NAN}).is_nan());
+/// assert_eq!(max_of!({a, b, c}.by_key(|s| s.len())), "three");
+/// ```
+#[macro_export]
+macro_rules! max_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        {
+            let (index, values) = $crate::__extremum!((argmax, argmax_by_key), {$($lh_sides),+} $($modifier)*);
+            $crate::extrema::take(values, index)
+        }
+    };
+}
+
+/// Find both the minimum and the maximum of a set of values and return them as a tuple `(min, max)`.
+///
+/// # Usage
+/// The usage is analogous to the [min_of](crate::min_of) macro and is documented in more detail there. The minimum and the maximum
+/// are the same as for [min_of](crate::min_of) and [max_of](crate::max_of), so the values must have the same type. Since the minimum and
+/// the maximum are the same value if the set contains only one value that is ordered with respect to itself, the values must also
+/// implement `Clone`, and the minimum is always a clone. Use [argmin_of](crate::argmin_of) and [argmax_of](crate::argmax_of) for values
+/// that can't be cloned. With `.by_key(key)`, the key is computed twice per value.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::minmax_of;
+/// // the following assertions hold
+/// assert_eq!(minmax_of!({3, 1, 2}), (1, 3));
+/// assert_eq!(minmax_of!({ 7 }), (7, 7));
+/// assert_eq!(minmax_of!({-3_i32, 1, 2}.by_key(|x| x.abs())), (1, -3));
+/// ```
+#[macro_export]
+macro_rules! minmax_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        {
+            let (indices, values) = $crate::__extremum!((argminmax, argminmax_by_key), {$($lh_sides),+} $($modifier)*);
+            $crate::extrema::take_pair(values, indices)
+        }
+    };
+}
+
+/// Find the position of the minimum in a set of values, counting from zero.
+///
+/// # Usage
+/// The usage is analogous to the [min_of](crate::min_of) macro and is documented in more detail there. This evaluates to the
+/// position of the value that [min_of](crate::min_of) returns, so it is the position of the first of several equal minimal values.
+/// The values need not implement `Clone` or `Copy`.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::argmin_of;
+/// // the following assertions hold
+/// assert_eq!(argmin_of!({3, 1, 2, 1}), 1);
+/// assert_eq!(argmin_of!({f64::NAN, 2.5, -1.}), 2);
+/// assert_eq!(argmin_of!({"three", "one", "four"}.map(str::len)), 1);
+/// ```
+#[macro_export]
+macro_rules! argmin_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__extremum!((argmin, argmin_by_key), {$($lh_sides),+} $($modifier)*).0
+    };
+}
+
+/// Find the position of the maximum in a set of values, counting from zero.
+///
+/// # Usage
+/// The usage is analogous to the [min_of](crate::min_of) macro and is documented in more detail there. This evaluates to the
+/// position of the value that [max_of](crate::max_of) returns, so it is the position of the last of several equal maximal values.
+///
+/// ## Examples
+/// ```
+/// # use fluent_comparisons_macros::argmax_of;
+/// // the following assertions hold
+/// assert_eq!(argmax_of!({3, 1, 3, 2}), 2);
+/// assert_eq!(argmax_of!({"three", "one", "four"}.by_key(|s| s.len())), 0);
+/// ```
+#[macro_export]
+macro_rules! argmax_of {
+    ( {$($lh_sides:expr),+ $(,)?} $($modifier:tt)* ) => {
+        $crate::__extremum!((argmax, argmax_by_key), {$($lh_sides),+} $($modifier)*).0
+    };
 }
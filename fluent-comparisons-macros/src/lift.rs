@@ -0,0 +1,60 @@
+//! Support traits for lifting comparisons through `Option` and `Result` values.
+//!
+//! Comparing `Option` values with the comparison operators compares the `Option`s themselves,
+//! so that e.g. `None < Some(x)` for all `x`. That is rarely what we want. The lifting forms of
+//! the macros, like `any_of!({a,b}? > x)` and `any_of!({a,b}.lift_or(false) > x)`, compare
+//! the contained values instead and use the [Lift] trait to do so.
+
+/// A value that might contain a value to compare, such as an `Option<T>` or a `Result<T,E>`.
+pub trait Lift {
+    /// The contained value.
+    type Value;
+    /// The type that a lifted comparison evaluates to. This is `Option<bool>` for an
+    /// `Option<T>` and `Result<bool,E>` for a `Result<T,E>`.
+    type Output: LiftOutput;
+
+    /// Obtain the contained value or the output that a lifted comparison short-circuits to
+    /// if there is no such value.
+    fn lift(self) -> Result<Self::Value, Self::Output>;
+}
+
+/// The result type of a lifted comparison, which can be constructed from the
+/// boolean result of the comparison.
+pub trait LiftOutput {
+    /// Create the output from the result of the comparison.
+    fn from_bool(value: bool) -> Self;
+}
+
+impl<T> Lift for Option<T> {
+    type Value = T;
+    type Output = Option<bool>;
+
+    #[inline]
+    fn lift(self) -> Result<T, Option<bool>> {
+        self.ok_or(None)
+    }
+}
+
+impl<T, E> Lift for Result<T, E> {
+    type Value = T;
+    type Output = Result<bool, E>;
+
+    #[inline]
+    fn lift(self) -> Result<T, Result<bool, E>> {
+        self.map_err(Err)
+    }
+}
+
+impl LiftOutput for Option<bool> {
+    #[inline]
+    fn from_bool(value: bool) -> Self {
+        Some(value)
+    }
+}
+
+impl<E> LiftOutput for Result<bool, E> {
+    #[inline]
+    fn from_bool(value: bool) -> Self {
+        Ok(value)
+    }
+}
@@ -0,0 +1,152 @@
+//! Support types for membership tests against sets of literals, like `any_of!(x == {1, 5, 9})`.
+
+/// A table of string slices that is sorted at compile time and searched using binary search.
+/// Membership tests against string literals use it to look up the value.
+/// ```
+/// # use fluent_comparisons_macros::membership::SortedStrs;
+/// const METHODS: SortedStrs<3> = SortedStrs::new(["POST", "GET", "HEAD"]);
+/// assert!(METHODS.contains("GET"));
+/// assert!(!METHODS.contains("PUT"));
+/// assert_eq!(METHODS.as_slice(), ["GET", "HEAD", "POST"]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SortedStrs<const N: usize>([&'static str; N]);
+
+impl<const N: usize> SortedStrs<N> {
+    /// Sort the given strings into a table. This is a `const fn`, so that the table can be
+    /// created at compile time.
+    pub const fn new(mut strs: [&'static str; N]) -> Self {
+        // insertion sort, since the sorting algorithms of the standard library are not const
+        let mut i = 1;
+        while i < N {
+            let mut j = i;
+            while j > 0 && const_cmp(strs[j - 1], strs[j]) > 0 {
+                let swapped = strs[j - 1];
+                strs[j - 1] = strs[j];
+                strs[j] = swapped;
+                j -= 1;
+            }
+            i += 1;
+        }
+        Self(strs)
+    }
+
+    /// Decide whether the table contains the given string using binary search. This is a `const fn`,
+    /// so that membership tests can be used in const contexts, too.
+    #[inline]
+    pub const fn contains(&self, value: &str) -> bool {
+        let (mut low, mut high) = (0, N);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match const_cmp(self.0[middle], value) {
+                0 => return true,
+                ordering if ordering < 0 => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+        false
+    }
+
+    /// Sort the literals of a membership test into a table if they are string literals. For other literals, the
+    /// table is empty and not used.
+    #[doc(hidden)]
+    pub const fn from_literals<T: Literal>(literals: [T; N]) -> Self {
+        let mut strs = [""; N];
+        if T::IS_STR {
+            let mut i = 0;
+            while i < N {
+                // SAFETY: `T` is a `&str` if `IS_STR` is true
+                strs[i] = unsafe {
+                    Cast {
+                        literal: literals[i],
+                    }
+                    .str
+                };
+                i += 1;
+            }
+        }
+        Self::new(strs)
+    }
+
+    /// The sorted strings of the table.
+    #[inline]
+    pub const fn as_slice(&self) -> &[&'static str] {
+        &self.0
+    }
+}
+
+/// The types of the literals in membership tests. String literals are looked up in a [SortedStrs] table, while all
+/// other literals are matched as patterns.
+pub trait Literal: Copy + private::Sealed {
+    #[doc(hidden)]
+    const IS_STR: bool;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_literal {
+    ($($t:ty),+) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Literal for $t {
+                const IS_STR: bool = false;
+            }
+        )+
+    };
+}
+
+impl_literal!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char, bool);
+impl_literal!(&[u8], &core::ffi::CStr);
+
+impl<const N: usize> private::Sealed for &[u8; N] {}
+impl<const N: usize> Literal for &[u8; N] {
+    const IS_STR: bool = false;
+}
+
+impl private::Sealed for &str {}
+impl Literal for &str {
+    const IS_STR: bool = true;
+}
+
+union Cast<T: Copy> {
+    literal: T,
+    str: &'static str,
+}
+
+/// Decide whether the value of a membership test is a string, which is then looked up using [contains].
+#[doc(hidden)]
+#[inline]
+pub const fn is_str<T: Literal>(_value: &T) -> bool {
+    T::IS_STR
+}
+
+/// Look up the value of a membership test against string literals in their table.
+#[doc(hidden)]
+#[inline]
+pub const fn contains<T: Literal, const N: usize>(table: &SortedStrs<N>, value: T) -> bool {
+    // SAFETY: `T` is a `&str` if `IS_STR` is true, and the reference does not outlive the call
+    T::IS_STR && table.contains(unsafe { Cast { literal: value }.str })
+}
+
+/// Compare two strings lexicographically by their bytes, which is the same order as the
+/// [Ord] implementation of `str`. Returns a negative number, zero, or a positive number if
+/// `lhs` is less than, equal to or greater than `rhs` respectively.
+const fn const_cmp(lhs: &str, rhs: &str) -> i8 {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    let mut i = 0;
+    while i < lhs.len() && i < rhs.len() {
+        if lhs[i] != rhs[i] {
+            return if lhs[i] < rhs[i] { -1 } else { 1 };
+        }
+        i += 1;
+    }
+    if lhs.len() < rhs.len() {
+        -1
+    } else if lhs.len() > rhs.len() {
+        1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,99 @@
+//! Support functions for the parallel macros, which are available with the `rayon` feature.
+//!
+//! The macros `par_any_of`, `par_all_of` and `par_none_of` split the set into halves until each value is left on its own
+//! and evaluate the halves in parallel on the [rayon](https://docs.rs/rayon) thread pool using a [Race].
+//!
+//! For values that are only known at runtime, the functions [any_of], [all_of] and [none_of] apply a predicate to
+//! the items of anything that rayon can iterate over in parallel, like a slice, a `Vec` or a range.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+use rayon::prelude::*;
+
+/// Evaluates the comparisons of a set in parallel until one of them returns the `decisive` value, which is `true`
+/// for `par_any_of` and `false` for `par_all_of`.
+#[derive(Debug)]
+pub struct Race {
+    decisive: bool,
+    decided: AtomicBool,
+}
+
+impl Race {
+    /// A race that is decided by the first comparison that returns the `decisive` value.
+    #[inline]
+    pub fn new(decisive: bool) -> Self {
+        Self {
+            decisive,
+            decided: AtomicBool::new(false),
+        }
+    }
+
+    /// Evaluate the comparison of a single value, unless the result is already known.
+    #[inline]
+    pub fn compare(&self, comparison: impl FnOnce() -> bool) -> bool {
+        if self.decided.load(Ordering::Relaxed) {
+            return self.decisive;
+        }
+        let result = comparison();
+        if result == self.decisive {
+            self.decided.store(true, Ordering::Relaxed);
+        }
+        result
+    }
+
+    /// Evaluate the comparisons of two halves of the set in parallel and combine their results.
+    #[inline]
+    pub fn join(
+        &self,
+        lhs: impl FnOnce() -> bool + Send,
+        rhs: impl FnOnce() -> bool + Send,
+    ) -> bool {
+        let (lhs, rhs) = rayon::join(lhs, rhs);
+        if self.decisive {
+            lhs || rhs
+        } else {
+            lhs && rhs
+        }
+    }
+}
+
+/// Decide in parallel whether the predicate holds for any of the values.
+///
+/// Once the predicate is `true` for a value, no further values are started.
+///
+/// ```
+/// # use fluent_comparisons_macros::parallel;
+/// let checksums = vec![12_u64, 7, 30];
+/// // the following assertions hold
+/// assert!(parallel::any_of(&checksums, |checksum| checksum % 2 == 1));
+/// assert!(parallel::all_of(0..1000_u64, |x| x * x >= x));
+/// assert!(parallel::none_of(checksums, |checksum| checksum > 100));
+/// ```
+#[inline]
+pub fn any_of<I: IntoParallelIterator>(
+    values: I,
+    predicate: impl Fn(I::Item) -> bool + Sync + Send,
+) -> bool {
+    values.into_par_iter().any(predicate)
+}
+
+/// Decide in parallel whether the predicate holds for all of the values.
+///
+/// Once the predicate is `false` for a value, no further values are started.
+#[inline]
+pub fn all_of<I: IntoParallelIterator>(
+    values: I,
+    predicate: impl Fn(I::Item) -> bool + Sync + Send,
+) -> bool {
+    values.into_par_iter().all(predicate)
+}
+
+/// Decide in parallel whether the predicate holds for none of the values.
+///
+/// Once the predicate is `true` for a value, no further values are started.
+#[inline]
+pub fn none_of<I: IntoParallelIterator>(
+    values: I,
+    predicate: impl Fn(I::Item) -> bool + Sync + Send,
+) -> bool {
+    !values.into_par_iter().any(predicate)
+}
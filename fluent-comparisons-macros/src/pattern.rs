@@ -0,0 +1,248 @@
+//! Support traits for matching the values of a set against regular expressions and glob patterns.
+//!
+//! With the `regex` feature, `any_of!({a,b}.matches_regex(&re))` decides whether any of the values matches the
+//! regular expression `re`, and with the `glob` feature, `none_of!({a,b}.matches_glob("*.rs"))` decides whether none of
+//! the values matches the glob pattern. The pattern is either given compiled, e.g. as a [regex::Regex] or a [glob::Pattern],
+//! or as a string, which is then compiled once per invocation of the macro and not once per value. Since a string might not
+//! be a valid pattern, the macros evaluate to a `Result` for patterns given as strings, which holds the error of compiling
+//! the pattern if it is invalid. For compiled patterns, they evaluate to a `bool` like the other forms.
+
+extern crate std;
+
+use core::borrow::Borrow;
+use core::convert::Infallible;
+#[cfg(feature = "glob")]
+use std::path::Path;
+use std::string::String;
+
+/// A regular expression or a string that can be compiled into one, i.e. a `&str`, a `String` or a `&String`.
+///
+/// ```
+/// # use fluent_comparisons_macros::{any_of,all_of};
+/// let forwarded = regex::Regex::new("^X-Forwarded-").unwrap();
+/// let (first, second) = ("X-Forwarded-For", String::from("Host"));
+/// // the following assertions hold
+/// assert!(any_of!({first, &second}.matches_regex(&forwarded)));
+/// assert!(!all_of!({first, &second}.matches_regex(&forwarded)));
+/// assert_eq!(all_of!({first, "x-forwarded-host"}.map(str::to_lowercase).matches_regex("^x-")), Ok(true));
+/// assert!(all_of!({first}.matches_regex("(unclosed")).is_err());
+/// ```
+#[cfg(feature = "regex")]
+pub trait IntoRegex: Sized {
+    /// The compiled regular expression or a reference to it.
+    type Regex: Borrow<regex::Regex>;
+    /// The error of compiling the regular expression, which is [Infallible] for a compiled regular expression.
+    type Error;
+    /// The result of the macro, which is `bool` for a compiled regular expression and `Result<bool, regex::Error>`
+    /// for a string.
+    type Output;
+
+    /// Obtain the compiled regular expression.
+    fn into_regex(self) -> Result<Self::Regex, Self::Error>;
+
+    /// Turn the result of the quantified comparison into the result of the macro.
+    fn into_output(result: Result<bool, Self::Error>) -> Self::Output;
+}
+
+#[cfg(feature = "regex")]
+impl IntoRegex for regex::Regex {
+    type Regex = regex::Regex;
+    type Error = Infallible;
+    type Output = bool;
+
+    #[inline]
+    fn into_regex(self) -> Result<Self::Regex, Infallible> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn into_output(result: Result<bool, Infallible>) -> bool {
+        infallible(result)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'a> IntoRegex for &'a regex::Regex {
+    type Regex = &'a regex::Regex;
+    type Error = Infallible;
+    type Output = bool;
+
+    #[inline]
+    fn into_regex(self) -> Result<Self::Regex, Infallible> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn into_output(result: Result<bool, Infallible>) -> bool {
+        infallible(result)
+    }
+}
+
+/// Implement [IntoRegex] for string types by compiling the string.
+#[cfg(feature = "regex")]
+macro_rules! impl_into_regex_for_strings {
+    ($($t:ty),+) => {
+        $(
+            impl IntoRegex for $t {
+                type Regex = regex::Regex;
+                type Error = regex::Error;
+                type Output = Result<bool, regex::Error>;
+
+                #[inline]
+                fn into_regex(self) -> Result<Self::Regex, regex::Error> {
+                    regex::Regex::new(self.as_ref())
+                }
+
+                #[inline]
+                fn into_output(result: Result<bool, regex::Error>) -> Self::Output {
+                    result
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "regex")]
+impl_into_regex_for_strings!(&str, String, &String);
+
+/// Compile the regular expression if necessary, together with the function that turns the result of the quantified
+/// comparison into the result of the macro.
+#[cfg(feature = "regex")]
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn compile_regex<P: IntoRegex>(
+    pattern: P,
+) -> (
+    Result<P::Regex, P::Error>,
+    fn(Result<bool, P::Error>) -> P::Output,
+) {
+    (pattern.into_regex(), P::into_output)
+}
+
+/// Decide whether the regular expression matches the value.
+#[cfg(feature = "regex")]
+#[inline]
+pub fn is_regex_match(regex: &impl Borrow<regex::Regex>, value: impl AsRef<str>) -> bool {
+    regex.borrow().is_match(value.as_ref())
+}
+
+/// A glob pattern or a string that can be compiled into one, i.e. a `&str`, a `String` or a `&String`.
+///
+/// ```
+/// # use fluent_comparisons_macros::{any_of,none_of};
+/// use std::path::Path;
+/// let (config, readme) = (Path::new("config/app.toml"), "README.md");
+/// let sources = glob::Pattern::new("src/**/*.rs").unwrap();
+/// // the following assertions hold
+/// assert!(none_of!({config, readme}.matches_glob(&sources)));
+/// assert_eq!(none_of!({config, readme}.matches_glob("*.rs")).ok(), Some(true));
+/// assert_eq!(any_of!({config, readme}.matches_glob("config/*.toml")).ok(), Some(true));
+/// assert!(any_of!({config, readme}.matches_glob("***")).is_err());
+/// ```
+#[cfg(feature = "glob")]
+pub trait IntoGlob: Sized {
+    /// The compiled glob pattern or a reference to it.
+    type Pattern: Borrow<glob::Pattern>;
+    /// The error of compiling the glob pattern, which is [Infallible] for a compiled glob pattern.
+    type Error;
+    /// The result of the macro, which is `bool` for a compiled glob pattern and `Result<bool, glob::PatternError>`
+    /// for a string.
+    type Output;
+
+    /// Obtain the compiled glob pattern.
+    fn into_glob(self) -> Result<Self::Pattern, Self::Error>;
+
+    /// Turn the result of the quantified comparison into the result of the macro.
+    fn into_output(result: Result<bool, Self::Error>) -> Self::Output;
+}
+
+#[cfg(feature = "glob")]
+impl IntoGlob for glob::Pattern {
+    type Pattern = glob::Pattern;
+    type Error = Infallible;
+    type Output = bool;
+
+    #[inline]
+    fn into_glob(self) -> Result<Self::Pattern, Infallible> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn into_output(result: Result<bool, Infallible>) -> bool {
+        infallible(result)
+    }
+}
+
+#[cfg(feature = "glob")]
+impl<'a> IntoGlob for &'a glob::Pattern {
+    type Pattern = &'a glob::Pattern;
+    type Error = Infallible;
+    type Output = bool;
+
+    #[inline]
+    fn into_glob(self) -> Result<Self::Pattern, Infallible> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn into_output(result: Result<bool, Infallible>) -> bool {
+        infallible(result)
+    }
+}
+
+/// Implement [IntoGlob] for string types by compiling the string.
+#[cfg(feature = "glob")]
+macro_rules! impl_into_glob_for_strings {
+    ($($t:ty),+) => {
+        $(
+            impl IntoGlob for $t {
+                type Pattern = glob::Pattern;
+                type Error = glob::PatternError;
+                type Output = Result<bool, glob::PatternError>;
+
+                #[inline]
+                fn into_glob(self) -> Result<Self::Pattern, glob::PatternError> {
+                    glob::Pattern::new(self.as_ref())
+                }
+
+                #[inline]
+                fn into_output(result: Result<bool, glob::PatternError>) -> Self::Output {
+                    result
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "glob")]
+impl_into_glob_for_strings!(&str, String, &String);
+
+/// Compile the glob pattern if necessary, together with the function that turns the result of the quantified
+/// comparison into the result of the macro.
+#[cfg(feature = "glob")]
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn compile_glob<P: IntoGlob>(
+    pattern: P,
+) -> (
+    Result<P::Pattern, P::Error>,
+    fn(Result<bool, P::Error>) -> P::Output,
+) {
+    (pattern.into_glob(), P::into_output)
+}
+
+/// Decide whether the glob pattern matches the value, which is a path or a string. A path that is not valid
+/// unicode never matches.
+#[cfg(feature = "glob")]
+#[inline]
+pub fn is_glob_match(pattern: &impl Borrow<glob::Pattern>, value: impl AsRef<Path>) -> bool {
+    pattern.borrow().matches_path(value.as_ref())
+}
+
+#[inline]
+fn infallible(result: Result<bool, Infallible>) -> bool {
+    match result {
+        Ok(result) => result,
+        Err(never) => match never {},
+    }
+}
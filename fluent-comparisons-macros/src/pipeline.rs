@@ -0,0 +1,20 @@
+//! Support functions for chains of transformations.
+//!
+//! The macros accept chains like `all_of!({a,b,c}.map(parse).filter(is_positive).satisfy(is_valid))`, which are applied
+//! to each value lazily from left to right. The steps are applied using the functions in this module rather than
+//! by calling the transformations directly, so that the compiler knows the argument type of a closure
+//! before it checks the body of the closure. That is what allows us to write e.g. `.filter(|x| *x > 0)` after a
+//! `.map(...)` that changes the type of the values.
+
+/// Apply a transformation to a value.
+#[inline]
+pub fn map<T, R>(value: T, func: impl FnOnce(T) -> R) -> R {
+    func(value)
+}
+
+/// Decide whether a value passes a filter. Like for [Iterator::filter], the predicate receives a reference
+/// to the value.
+#[inline]
+pub fn filter<T>(value: &T, predicate: impl FnOnce(&T) -> bool) -> bool {
+    predicate(value)
+}
@@ -0,0 +1,181 @@
+use super::{Comparison, Operand, Operator, Quantified, Quantifier, Range, Registry, Rule};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+
+/// The variables that a rule is evaluated against, which are looked up by name.
+///
+/// This is implemented for closures `Fn(&str) -> Option<f64>` and for maps from names to values.
+pub trait Variables {
+    /// The value of the variable, or `None` if there is no variable of that name.
+    fn value(&self, name: &str) -> Option<f64>;
+}
+
+impl<F: Fn(&str) -> Option<f64> + ?Sized> Variables for F {
+    #[inline]
+    fn value(&self, name: &str) -> Option<f64> {
+        self(name)
+    }
+}
+
+impl<K: Borrow<str> + Ord> Variables for BTreeMap<K, f64> {
+    #[inline]
+    fn value(&self, name: &str) -> Option<f64> {
+        self.get(name).copied()
+    }
+}
+
+/// An error that occurred while evaluating a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// A variable that had to be looked up, but is not defined.
+    UnknownVariable(String),
+    /// A transformation that is not registered in the registry.
+    UnknownMap(String),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UnknownVariable(name) => write!(f, "unknown variable `{}`", name),
+            EvalError::UnknownMap(name) => write!(f, "unknown map `{}`", name),
+        }
+    }
+}
+
+impl core::error::Error for EvalError {}
+
+impl Rule {
+    /// Evaluate the rule lazily against the variables, using the transformations in the registry.
+    ///
+    /// Only the variables that are needed to decide the result are looked up, so a variable that is not defined
+    /// is an error only if it is looked up. The transformations of a quantified comparison are looked up in the registry before
+    /// its values, so a transformation that is not registered is always an error if the quantified comparison is evaluated.
+    pub fn evaluate<V: Variables + ?Sized>(
+        &self,
+        variables: &V,
+        registry: &Registry,
+    ) -> Result<bool, EvalError> {
+        match self {
+            Rule::Quantified(quantified) => quantified.evaluate(variables, registry),
+            Rule::Not(rule) => Ok(!rule.evaluate(variables, registry)?),
+            Rule::And(lhs, rhs) => {
+                Ok(lhs.evaluate(variables, registry)? && rhs.evaluate(variables, registry)?)
+            }
+            Rule::Or(lhs, rhs) => {
+                Ok(lhs.evaluate(variables, registry)? || rhs.evaluate(variables, registry)?)
+            }
+        }
+    }
+}
+
+impl Quantified {
+    /// Evaluate the quantified comparison lazily from left to right, like the macros.
+    pub fn evaluate<V: Variables + ?Sized>(
+        &self,
+        variables: &V,
+        registry: &Registry,
+    ) -> Result<bool, EvalError> {
+        self.evaluate_recorded(variables, registry, |_, _, _| {})
+    }
+
+    /// Evaluate the quantified comparison and pass each evaluated operand to `record`, together with
+    /// its transformed value and whether it satisfied the comparison.
+    pub(crate) fn evaluate_recorded<V: Variables + ?Sized>(
+        &self,
+        variables: &V,
+        registry: &Registry,
+        mut record: impl FnMut(&Operand, f64, bool),
+    ) -> Result<bool, EvalError> {
+        let maps = self
+            .maps
+            .iter()
+            .map(|name| {
+                registry
+                    .map(name)
+                    .ok_or_else(|| EvalError::UnknownMap(name.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut satisfies = |operand: &Operand| -> Result<bool, EvalError> {
+            let value = maps
+                .iter()
+                .fold(operand.evaluate(variables)?, |value, map| map(value));
+            let satisfied = match &self.comparison {
+                Comparison::Compare(operator, rhs) => {
+                    operator.compare(&value, &rhs.evaluate(variables)?)
+                }
+                Comparison::InRange(range) => range.contains(value, variables)?,
+            };
+            record(operand, value, satisfied);
+            Ok(satisfied)
+        };
+        // any_of stops at the first satisfied comparison, all_of and none_of stop at the first decisive one
+        let decisive = match self.quantifier {
+            Quantifier::AnyOf | Quantifier::NoneOf => true,
+            Quantifier::AllOf => false,
+        };
+        for operand in &self.set {
+            if satisfies(operand)? == decisive {
+                return Ok(self.quantifier == Quantifier::AnyOf);
+            }
+        }
+        Ok(self.quantifier != Quantifier::AnyOf)
+    }
+}
+
+impl Operand {
+    /// The number, or the value of the variable.
+    pub fn evaluate<V: Variables + ?Sized>(&self, variables: &V) -> Result<f64, EvalError> {
+        match self {
+            Operand::Variable(name) => variables
+                .value(name)
+                .ok_or_else(|| EvalError::UnknownVariable(name.clone())),
+            Operand::Number(number) => Ok(*number),
+        }
+    }
+}
+
+impl Operator {
+    /// Compare the values using the operator.
+    #[inline]
+    pub fn compare<T: PartialOrd + ?Sized>(self, lhs: &T, rhs: &T) -> bool {
+        match self {
+            Operator::Eq => lhs == rhs,
+            Operator::Ne => lhs != rhs,
+            Operator::Lt => lhs < rhs,
+            Operator::Le => lhs <= rhs,
+            Operator::Gt => lhs > rhs,
+            Operator::Ge => lhs >= rhs,
+        }
+    }
+}
+
+impl Range {
+    /// Decide whether the range contains the value, after evaluating the start and then the end of the range.
+    pub fn contains<V: Variables + ?Sized>(
+        &self,
+        value: f64,
+        variables: &V,
+    ) -> Result<bool, EvalError> {
+        let start = self
+            .start
+            .as_ref()
+            .map(|start| start.evaluate(variables))
+            .transpose()?;
+        let end = self
+            .end
+            .as_ref()
+            .map(|end| end.evaluate(variables))
+            .transpose()?;
+        Ok(match (start, end, self.inclusive) {
+            (Some(start), Some(end), false) => (start..end).contains(&value),
+            (Some(start), Some(end), true) => (start..=end).contains(&value),
+            (Some(start), None, _) => (start..).contains(&value),
+            (None, Some(end), false) => (..end).contains(&value),
+            (None, Some(end), true) => (..=end).contains(&value),
+            (None, None, _) => true,
+        })
+    }
+}
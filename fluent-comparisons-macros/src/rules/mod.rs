@@ -0,0 +1,199 @@
+//! Parse and evaluate quantified comparisons that are only known at runtime.
+//!
+//! The macros need the comparisons at compile time, which rules out conditions that are configured in plain text, like
+//! alert conditions. With the `rules` feature, this module parses such conditions using the syntax of the macros into a
+//! [Rule] and evaluates it against numeric variables that are looked up by name. A rule is built from quantified comparisons
+//! like `any_of({cpu, mem} > 90)`, which can be combined with `&&`, `||`, `!` and parentheses like boolean expressions in Rust.
+//! The quantified comparisons follow the syntax of [any_of](crate::any_of), [all_of](crate::all_of) and [none_of](crate::none_of),
+//! with an optional `!` after the name of the quantifier:
+//!
+//! * The set and the right hand side consist of variables and number literals, like `{cpu, mem, 42.5}`.
+//! * The comparison operators are `==`, `!=`, `<=`, `<`, `>` and `>=`.
+//! * A chain of transformations `.map(name)` can follow the set, where the functions are registered by name in a [Registry].
+//! * Instead of a comparison operator and a right hand side, `in lo..hi` tests whether the values are contained in a range.
+//!   All forms of ranges except the full range `..` are allowed, e.g. `in 5..=10` or `in ..limit`. This is equivalent to
+//!   `.satisfy(|x| (lo..hi).contains(&x))` in the macros.
+//!
+//! Parsing fails for rules that are nested deeper than [MAX_NESTING] levels, so that parsing, evaluating and dropping
+//! them can't overflow the stack.
+//!
+//! Rules are evaluated with the same lazy semantics as the macros. The operands are evaluated from left to right, where each value
+//! of the set is looked up and transformed before the right hand side or the bounds of the range are looked up for its comparison.
+//! The evaluation stops as soon as the result is known, so variables that don't affect the result are not looked up. Like for
+//! the comparison operators of `f64`, comparisons with `NaN` are `false`, except for `!=`.
+//!
+//! Besides the result, [Rule::explain] reports which values were compared and how. With the `serde` feature, rules and reports
+//! implement `Serialize` and `Deserialize`, so that they can be stored in configuration files or logged. Wrap them in [Versioned]
+//! to store them together with the version of their schema. Deserialization rejects empty sets and full ranges, but it can't
+//! check the names of the transformations, so pass deserialized rules to [Rule::validate] before evaluating them. Note that formats like JSON can't represent `NaN`, which can occur in the values of reports.
+//!
+//! ```
+//! # use fluent_comparisons_macros::rules::{Registry, Rule};
+//! # use std::collections::BTreeMap;
+//! let registry = Registry::new().with_map("percent", |x| 100. * x);
+//! let rule = Rule::parse("any_of!({cpu, mem}.map(percent) > 90) && none_of!({disk} in ..5)", &registry).unwrap();
+//!
+//! let mut usage = BTreeMap::new();
+//! usage.insert("cpu", 0.4);
+//! usage.insert("mem", 0.95);
+//! usage.insert("disk", 20.);
+//! assert_eq!(rule.evaluate(&usage, &registry), Ok(true));
+//!
+//! let lookup = |name: &str| if name == "cpu" { Some(0.99) } else { None };
+//! // the variables mem and disk are never looked up
+//! assert_eq!(Rule::parse("any_of({cpu, mem} > 0.9)", &registry).unwrap().evaluate(&lookup, &registry), Ok(true));
+//! ```
+
+mod eval;
+mod parse;
+mod report;
+#[cfg(feature = "serde")]
+mod schema;
+
+pub use eval::{EvalError, Variables};
+pub use parse::{ParseError, ParseErrorKind, MAX_NESTING};
+pub use report::{ComparisonExplanation, Explanation, QuantifiedExplanation, Report};
+#[cfg(feature = "serde")]
+pub use schema::{Versioned, SCHEMA_VERSION};
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A rule that combines quantified comparisons with boolean operators.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Rule {
+    /// A quantified comparison like `any_of({a, b} > c)`.
+    Quantified(Quantified),
+    /// The negation `!rule`.
+    Not(Box<Rule>),
+    /// The lazy conjunction `lhs && rhs`.
+    And(Box<Rule>, Box<Rule>),
+    /// The lazy disjunction `lhs || rhs`.
+    Or(Box<Rule>, Box<Rule>),
+}
+
+/// A quantified comparison of a set of values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "schema::QuantifiedFields"))]
+pub struct Quantified {
+    /// The quantifier over the values of the set.
+    pub quantifier: Quantifier,
+    /// The values of the set, which is never empty.
+    pub set: Vec<Operand>,
+    /// The names of the transformations that are applied to each value in order.
+    pub maps: Vec<String>,
+    /// The comparison of each transformed value.
+    pub comparison: Comparison,
+}
+
+/// The quantifier of a quantified comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Quantifier {
+    /// `any_of`
+    AnyOf,
+    /// `all_of`
+    AllOf,
+    /// `none_of`
+    NoneOf,
+}
+
+/// A value in a set or a right hand side.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Operand {
+    /// A variable that is looked up by name.
+    Variable(String),
+    /// A number literal.
+    Number(f64),
+}
+
+/// The comparison that is applied to each value of a set.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Comparison {
+    /// Compare the value to the right hand side using the operator.
+    Compare(Operator, Operand),
+    /// Test whether the value is contained in the range.
+    InRange(Range),
+}
+
+/// A comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Operator {
+    /// `==`
+    #[cfg_attr(feature = "serde", serde(rename = "=="))]
+    Eq,
+    /// `!=`
+    #[cfg_attr(feature = "serde", serde(rename = "!="))]
+    Ne,
+    /// `<`
+    #[cfg_attr(feature = "serde", serde(rename = "<"))]
+    Lt,
+    /// `<=`
+    #[cfg_attr(feature = "serde", serde(rename = "<="))]
+    Le,
+    /// `>`
+    #[cfg_attr(feature = "serde", serde(rename = ">"))]
+    Gt,
+    /// `>=`
+    #[cfg_attr(feature = "serde", serde(rename = ">="))]
+    Ge,
+}
+
+/// A range of values with at least one bound, like `lo..hi` or `..=hi`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "schema::RangeFields"))]
+pub struct Range {
+    /// The inclusive lower bound, if any.
+    pub start: Option<Operand>,
+    /// The upper bound, if any.
+    pub end: Option<Operand>,
+    /// Whether the upper bound is inclusive, like for `lo..=hi`.
+    pub inclusive: bool,
+}
+
+/// The named transformations that rules can apply with `.map(name)`.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    maps: BTreeMap<String, fn(f64) -> f64>,
+}
+
+impl Registry {
+    /// A registry without any transformations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a transformation under the given name, replacing a previous transformation of the same name.
+    pub fn with_map(mut self, name: impl Into<String>, map: fn(f64) -> f64) -> Self {
+        self.insert_map(name, map);
+        self
+    }
+
+    /// Register a transformation under the given name and return the previous transformation of the same name, if any.
+    pub fn insert_map(
+        &mut self,
+        name: impl Into<String>,
+        map: fn(f64) -> f64,
+    ) -> Option<fn(f64) -> f64> {
+        self.maps.insert(name.into(), map)
+    }
+
+    /// The transformation of the given name, if it is registered.
+    pub fn map(&self, name: &str) -> Option<fn(f64) -> f64> {
+        self.maps.get(name).copied()
+    }
+}
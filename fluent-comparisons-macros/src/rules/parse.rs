@@ -0,0 +1,551 @@
+use super::{Comparison, Operand, Operator, Quantified, Quantifier, Range, Registry, Rule};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The maximum nesting of a rule. It limits both how many negations and parentheses may enclose a part of the text and
+/// the depth of the tree of the parsed rule, where each negation, `&&` and `||` adds a level. The limit keeps the
+/// recursive parsing, evaluation and dropping of rules from overflowing the stack.
+pub const MAX_NESTING: usize = 64;
+
+/// The reason why a rule could not be parsed or is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A character that can not start a token.
+    UnexpectedCharacter(char),
+    /// A token other than the expected one, where `found` is the text of the token or `end of input`.
+    Expected {
+        /// A description of what was expected.
+        expected: &'static str,
+        /// The text of the token that was found instead.
+        found: String,
+    },
+    /// An identifier that is not `any_of`, `all_of` or `none_of` in place of a quantifier.
+    UnknownQuantifier(String),
+    /// A transformation that is not registered in the registry.
+    UnknownMap(String),
+    /// The full range `..`, which contains all values.
+    FullRange,
+    /// A quantified comparison without any values in its set, which the syntax can't express.
+    EmptySet,
+    /// A rule that is nested deeper than [MAX_NESTING], where the error points at the operator or parenthesis
+    /// that exceeds the limit.
+    TooDeeplyNested,
+}
+
+/// An error that occurred while parsing a rule, at a line and column of the text, which both start at one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The reason for the error.
+    pub kind: ParseErrorKind,
+    /// The line of the text where the error occurred.
+    pub line: usize,
+    /// The column of the line where the error occurred, counted in characters.
+    pub column: usize,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedCharacter(character) => {
+                write!(f, "unexpected character `{}`", character)
+            }
+            ParseErrorKind::Expected { expected, found } => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            ParseErrorKind::UnknownQuantifier(name) => write!(
+                f,
+                "unknown quantifier `{}`, expected `any_of`, `all_of` or `none_of`",
+                name
+            ),
+            ParseErrorKind::UnknownMap(name) => write!(f, "unknown map `{}`", name),
+            ParseErrorKind::FullRange => write!(f, "the full range `..` is not allowed"),
+            ParseErrorKind::EmptySet => write!(f, "the set must contain at least one value"),
+            ParseErrorKind::TooDeeplyNested => {
+                write!(f, "the rule is nested deeper than {} levels", MAX_NESTING)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.line, self.column
+        )
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Rule {
+    /// Parse a rule, where the names of the transformations must be registered in the registry.
+    pub fn parse(text: &str, registry: &Registry) -> Result<Rule, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+            depth: 0,
+            registry,
+        };
+        let (rule, _) = parser.disjunction()?;
+        parser.expect(TokenKind::End, "`&&`, `||` or end of input")?;
+        Ok(rule)
+    }
+
+    /// Check a rule that was not parsed, e.g. a deserialized one, the same way as [Rule::parse] does: the names of
+    /// the transformations must be registered in the registry, the sets must not be empty, the ranges must have a
+    /// bound and the rule must not be nested deeper than [MAX_NESTING].
+    pub fn validate(&self, registry: &Registry) -> Result<(), ParseErrorKind> {
+        self.validate_at(0, registry)
+    }
+
+    fn validate_at(&self, depth: usize, registry: &Registry) -> Result<(), ParseErrorKind> {
+        if depth > MAX_NESTING {
+            return Err(ParseErrorKind::TooDeeplyNested);
+        }
+        match self {
+            Rule::Quantified(quantified) => quantified.validate(registry),
+            Rule::Not(rule) => rule.validate_at(depth + 1, registry),
+            Rule::And(lhs, rhs) | Rule::Or(lhs, rhs) => {
+                lhs.validate_at(depth + 1, registry)?;
+                rhs.validate_at(depth + 1, registry)
+            }
+        }
+    }
+}
+
+impl Quantified {
+    /// Create a quantified comparison, which fails for an empty set like [Rule::parse] does.
+    pub fn new(
+        quantifier: Quantifier,
+        set: Vec<Operand>,
+        maps: Vec<String>,
+        comparison: Comparison,
+    ) -> Result<Self, ParseErrorKind> {
+        if set.is_empty() {
+            return Err(ParseErrorKind::EmptySet);
+        }
+        Ok(Quantified {
+            quantifier,
+            set,
+            maps,
+            comparison,
+        })
+    }
+
+    fn validate(&self, registry: &Registry) -> Result<(), ParseErrorKind> {
+        if self.set.is_empty() {
+            return Err(ParseErrorKind::EmptySet);
+        }
+        if let Some(name) = self.maps.iter().find(|name| registry.map(name).is_none()) {
+            return Err(ParseErrorKind::UnknownMap(name.clone()));
+        }
+        match &self.comparison {
+            Comparison::InRange(Range {
+                start: None,
+                end: None,
+                ..
+            }) => Err(ParseErrorKind::FullRange),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Range {
+    /// Create a range, which fails for the full range `..` like [Rule::parse] does.
+    pub fn new(
+        start: Option<Operand>,
+        end: Option<Operand>,
+        inclusive: bool,
+    ) -> Result<Self, ParseErrorKind> {
+        if start.is_none() && end.is_none() {
+            return Err(ParseErrorKind::FullRange);
+        }
+        Ok(Range {
+            start,
+            end,
+            inclusive,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Identifier,
+    Number(f64),
+    Operator(Operator),
+    OpenParen,
+    CloseParen,
+    OpenBrace,
+    CloseBrace,
+    Comma,
+    Dot,
+    DotDot,
+    DotDotEq,
+    Bang,
+    Minus,
+    AndAnd,
+    OrOr,
+    End,
+}
+
+#[derive(Debug, Clone)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            kind,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn found(&self) -> String {
+        match self.kind {
+            TokenKind::End => "end of input".to_string(),
+            _ => alloc::format!("`{}`", self.text),
+        }
+    }
+}
+
+/// Split the text into tokens, which always end with a token of kind [TokenKind::End].
+fn tokenize(text: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut characters = text.char_indices().peekable();
+    let (mut line, mut column) = (1, 1);
+    while let Some((start, character)) = characters.next() {
+        let (token_line, token_column) = (line, column);
+        let mut end = start + character.len_utf8();
+        let mut next_if = |expected: char| match characters.peek() {
+            Some(&(index, next)) if next == expected => {
+                characters.next();
+                end = index + next.len_utf8();
+                true
+            }
+            _ => false,
+        };
+        let kind = match character {
+            '\n' => {
+                line += 1;
+                column = 1;
+                continue;
+            }
+            _ if character.is_whitespace() => {
+                column += 1;
+                continue;
+            }
+            '(' => TokenKind::OpenParen,
+            ')' => TokenKind::CloseParen,
+            '{' => TokenKind::OpenBrace,
+            '}' => TokenKind::CloseBrace,
+            ',' => TokenKind::Comma,
+            '-' => TokenKind::Minus,
+            '.' if next_if('.') => {
+                if next_if('=') {
+                    TokenKind::DotDotEq
+                } else {
+                    TokenKind::DotDot
+                }
+            }
+            '.' => TokenKind::Dot,
+            '!' if next_if('=') => TokenKind::Operator(Operator::Ne),
+            '!' => TokenKind::Bang,
+            '=' if next_if('=') => TokenKind::Operator(Operator::Eq),
+            '<' if next_if('=') => TokenKind::Operator(Operator::Le),
+            '<' => TokenKind::Operator(Operator::Lt),
+            '>' if next_if('=') => TokenKind::Operator(Operator::Ge),
+            '>' => TokenKind::Operator(Operator::Gt),
+            '&' if next_if('&') => TokenKind::AndAnd,
+            '|' if next_if('|') => TokenKind::OrOr,
+            _ if character.is_ascii_alphabetic() || character == '_' => {
+                while let Some(&(index, next)) = characters.peek() {
+                    if !(next.is_ascii_alphanumeric() || next == '_') {
+                        break;
+                    }
+                    characters.next();
+                    end = index + 1;
+                }
+                TokenKind::Identifier
+            }
+            _ if character.is_ascii_digit() => {
+                end = number_end(text, start);
+                while characters.peek().is_some_and(|&(index, _)| index < end) {
+                    characters.next();
+                }
+                let digits: String = text[start..end].chars().filter(|&c| c != '_').collect();
+                TokenKind::Number(
+                    digits
+                        .parse()
+                        .expect("the digits of a number literal are a valid f64"),
+                )
+            }
+            _ => {
+                return Err(ParseError {
+                    kind: ParseErrorKind::UnexpectedCharacter(character),
+                    line: token_line,
+                    column: token_column,
+                })
+            }
+        };
+        column += text[start..end].chars().count();
+        tokens.push(Token {
+            kind,
+            text: &text[start..end],
+            line: token_line,
+            column: token_column,
+        });
+    }
+    tokens.push(Token {
+        kind: TokenKind::End,
+        text: "",
+        line,
+        column,
+    });
+    Ok(tokens)
+}
+
+/// The end of the number literal that starts at the given index, like `1_000`, `2.5` or `1e-3`. A dot only
+/// belongs to the number if a digit follows it, so that `1..2` is a range.
+fn number_end(text: &str, start: usize) -> usize {
+    let bytes = text.as_bytes();
+    let digits_end = |mut index: usize| {
+        while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'_') {
+            index += 1;
+        }
+        index
+    };
+    let mut end = digits_end(start);
+    if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).is_some_and(u8::is_ascii_digit) {
+        end = digits_end(end + 1);
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let mut exponent = end + 1;
+        if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+            exponent += 1;
+        }
+        if bytes.get(exponent).is_some_and(u8::is_ascii_digit) {
+            end = digits_end(exponent);
+        }
+    }
+    end
+}
+
+/// A recursive descent parser, where each method parses one rule of the grammar and returns it together with
+/// the height of its tree:
+///
+/// ```text
+/// disjunction := conjunction ("||" conjunction)*
+/// conjunction := negation ("&&" negation)*
+/// negation    := "!" negation | "(" disjunction ")" | quantified
+/// quantified  := quantifier "!"? "(" "{" operand ("," operand)* ","? "}" (".map(" name ")")* comparison ")"
+/// comparison  := operator operand | "in" operand? (".." | "..=") operand?
+/// operand     := variable | "-"? number
+/// ```
+struct Parser<'a, 'r> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+    /// The number of negations and parentheses that enclose the current position.
+    depth: usize,
+    registry: &'r Registry,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn peek(&self) -> &Token<'a> {
+        &self.tokens[self.position]
+    }
+
+    fn next(&mut self) -> Token<'a> {
+        let token = self.tokens[self.position].clone();
+        if token.kind != TokenKind::End {
+            self.position += 1;
+        }
+        token
+    }
+
+    /// Consume the next token if it is of the given kind.
+    fn next_if(&mut self, kind: TokenKind) -> bool {
+        let matches = self.peek().kind == kind;
+        if matches {
+            self.next();
+        }
+        matches
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &'static str) -> Result<Token<'a>, ParseError> {
+        let token = self.next();
+        if token.kind == kind {
+            Ok(token)
+        } else {
+            Err(token.error(ParseErrorKind::Expected {
+                expected,
+                found: token.found(),
+            }))
+        }
+    }
+
+    fn expect_identifier(&mut self, text: &str, expected: &'static str) -> Result<(), ParseError> {
+        let token = self.expect(TokenKind::Identifier, expected)?;
+        if token.text == text {
+            Ok(())
+        } else {
+            Err(token.error(ParseErrorKind::Expected {
+                expected,
+                found: token.found(),
+            }))
+        }
+    }
+
+    /// The height of a rule whose highest operand has the given height, if it is within the limit.
+    fn nest(token: &Token<'_>, height: usize) -> Result<usize, ParseError> {
+        if height < MAX_NESTING {
+            Ok(height + 1)
+        } else {
+            Err(token.error(ParseErrorKind::TooDeeplyNested))
+        }
+    }
+
+    fn disjunction(&mut self) -> Result<(Rule, usize), ParseError> {
+        let (mut rule, mut height) = self.conjunction()?;
+        while self.peek().kind == TokenKind::OrOr {
+            let token = self.next();
+            let (rhs, rhs_height) = self.conjunction()?;
+            height = Self::nest(&token, height.max(rhs_height))?;
+            rule = Rule::Or(Box::new(rule), Box::new(rhs));
+        }
+        Ok((rule, height))
+    }
+
+    fn conjunction(&mut self) -> Result<(Rule, usize), ParseError> {
+        let (mut rule, mut height) = self.negation()?;
+        while self.peek().kind == TokenKind::AndAnd {
+            let token = self.next();
+            let (rhs, rhs_height) = self.negation()?;
+            height = Self::nest(&token, height.max(rhs_height))?;
+            rule = Rule::And(Box::new(rule), Box::new(rhs));
+        }
+        Ok((rule, height))
+    }
+
+    fn negation(&mut self) -> Result<(Rule, usize), ParseError> {
+        let token = self.peek().clone();
+        if !matches!(token.kind, TokenKind::Bang | TokenKind::OpenParen) {
+            return Ok((Rule::Quantified(self.quantified()?), 0));
+        }
+        // the height of the rule is at least the depth, so this stops the recursion before it gets too deep
+        self.depth = Self::nest(&token, self.depth)?;
+        self.next();
+        let (rule, height) = if token.kind == TokenKind::Bang {
+            let (rule, height) = self.negation()?;
+            (Rule::Not(Box::new(rule)), Self::nest(&token, height)?)
+        } else {
+            let rule = self.disjunction()?;
+            self.expect(TokenKind::CloseParen, "`)`")?;
+            rule
+        };
+        self.depth -= 1;
+        Ok((rule, height))
+    }
+
+    fn quantified(&mut self) -> Result<Quantified, ParseError> {
+        let token = self.expect(TokenKind::Identifier, "a quantified comparison")?;
+        let quantifier = match token.text {
+            "any_of" => Quantifier::AnyOf,
+            "all_of" => Quantifier::AllOf,
+            "none_of" => Quantifier::NoneOf,
+            name => return Err(token.error(ParseErrorKind::UnknownQuantifier(name.to_string()))),
+        };
+        self.next_if(TokenKind::Bang);
+        self.expect(TokenKind::OpenParen, "`(`")?;
+        self.expect(TokenKind::OpenBrace, "`{`")?;
+        let mut set = alloc::vec![self.operand()?];
+        while self.next_if(TokenKind::Comma) && self.peek().kind != TokenKind::CloseBrace {
+            set.push(self.operand()?);
+        }
+        self.expect(TokenKind::CloseBrace, "`,` or `}`")?;
+        let mut maps = Vec::new();
+        while self.next_if(TokenKind::Dot) {
+            self.expect_identifier("map", "`map`")?;
+            self.expect(TokenKind::OpenParen, "`(`")?;
+            let name = self.expect(TokenKind::Identifier, "the name of a map")?;
+            if self.registry.map(name.text).is_none() {
+                return Err(name.error(ParseErrorKind::UnknownMap(name.text.to_string())));
+            }
+            maps.push(name.text.to_string());
+            self.expect(TokenKind::CloseParen, "`)`")?;
+        }
+        let comparison = self.comparison()?;
+        self.expect(TokenKind::CloseParen, "`)`")?;
+        Quantified::new(quantifier, set, maps, comparison).map_err(|kind| token.error(kind))
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, ParseError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Operator(operator) => Ok(Comparison::Compare(operator, self.operand()?)),
+            TokenKind::Identifier if token.text == "in" => self.range().map(Comparison::InRange),
+            _ => Err(token.error(ParseErrorKind::Expected {
+                expected: "`.map(...)`, a comparison operator or `in`",
+                found: token.found(),
+            })),
+        }
+    }
+
+    fn range(&mut self) -> Result<Range, ParseError> {
+        let start = self.starts_operand().then(|| self.operand()).transpose()?;
+        let token = self.next();
+        let inclusive = match token.kind {
+            TokenKind::DotDot => false,
+            TokenKind::DotDotEq => true,
+            _ => {
+                return Err(token.error(ParseErrorKind::Expected {
+                    expected: "`..` or `..=`",
+                    found: token.found(),
+                }))
+            }
+        };
+        let end = if inclusive || self.starts_operand() {
+            Some(self.operand()?)
+        } else {
+            None
+        };
+        Range::new(start, end, inclusive).map_err(|kind| token.error(kind))
+    }
+
+    fn starts_operand(&self) -> bool {
+        matches!(
+            self.peek().kind,
+            TokenKind::Identifier | TokenKind::Number(_) | TokenKind::Minus
+        )
+    }
+
+    fn operand(&mut self) -> Result<Operand, ParseError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Identifier => Ok(Operand::Variable(token.text.to_string())),
+            TokenKind::Number(number) => Ok(Operand::Number(number)),
+            TokenKind::Minus => {
+                let token = self.next();
+                match token.kind {
+                    TokenKind::Number(number) => Ok(Operand::Number(-number)),
+                    _ => Err(token.error(ParseErrorKind::Expected {
+                        expected: "a number",
+                        found: token.found(),
+                    })),
+                }
+            }
+            _ => Err(token.error(ParseErrorKind::Expected {
+                expected: "a variable or a number",
+                found: token.found(),
+            })),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Explanations of how the result of a rule was obtained, see [Rule::explain].
+
+use super::{EvalError, Operand, Quantified, Quantifier, Registry, Rule, Variables};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The result of evaluating a rule, together with an explanation of how it was obtained.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Report {
+    /// The result of the rule.
+    pub result: bool,
+    /// The parts of the rule that were evaluated.
+    pub explanation: Explanation,
+}
+
+/// The parts of a rule that were evaluated, which mirrors the structure of the [Rule]. Parts that were skipped,
+/// because the result was already known, are missing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Explanation {
+    /// A quantified comparison.
+    Quantified(QuantifiedExplanation),
+    /// The negation of a rule.
+    Not(Box<Explanation>),
+    /// A conjunction, where the right hand side is missing if the left hand side is `false`.
+    And(Box<Explanation>, Option<Box<Explanation>>),
+    /// A disjunction, where the right hand side is missing if the left hand side is `true`.
+    Or(Box<Explanation>, Option<Box<Explanation>>),
+}
+
+/// The comparisons of a quantified comparison that were evaluated.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuantifiedExplanation {
+    /// The quantifier over the values of the set.
+    pub quantifier: Quantifier,
+    /// The result of the quantified comparison.
+    pub result: bool,
+    /// The evaluated comparisons from left to right, where the last one decided the result if the evaluation stopped early.
+    pub comparisons: Vec<ComparisonExplanation>,
+}
+
+/// A comparison of one value of a set.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComparisonExplanation {
+    /// The value in the set.
+    pub operand: Operand,
+    /// The value after looking it up and applying the transformations.
+    pub value: f64,
+    /// Whether the value satisfied the comparison.
+    pub satisfied: bool,
+}
+
+impl Explanation {
+    /// The result of the explained part of the rule.
+    pub fn result(&self) -> bool {
+        match self {
+            Explanation::Quantified(quantified) => quantified.result,
+            Explanation::Not(explanation) => !explanation.result(),
+            Explanation::And(lhs, rhs) | Explanation::Or(lhs, rhs) => {
+                rhs.as_ref().map_or(lhs.result(), |rhs| rhs.result())
+            }
+        }
+    }
+}
+
+impl Rule {
+    /// Evaluate the rule like [Rule::evaluate] and explain the result.
+    pub fn explain<V: Variables + ?Sized>(
+        &self,
+        variables: &V,
+        registry: &Registry,
+    ) -> Result<Report, EvalError> {
+        let explanation = self.explanation(variables, registry)?;
+        Ok(Report {
+            result: explanation.result(),
+            explanation,
+        })
+    }
+
+    fn explanation<V: Variables + ?Sized>(
+        &self,
+        variables: &V,
+        registry: &Registry,
+    ) -> Result<Explanation, EvalError> {
+        Ok(match self {
+            Rule::Quantified(quantified) => {
+                Explanation::Quantified(quantified.explanation(variables, registry)?)
+            }
+            Rule::Not(rule) => Explanation::Not(Box::new(rule.explanation(variables, registry)?)),
+            Rule::And(lhs, rhs) => {
+                let lhs = lhs.explanation(variables, registry)?;
+                let rhs = match lhs.result() {
+                    true => Some(Box::new(rhs.explanation(variables, registry)?)),
+                    false => None,
+                };
+                Explanation::And(Box::new(lhs), rhs)
+            }
+            Rule::Or(lhs, rhs) => {
+                let lhs = lhs.explanation(variables, registry)?;
+                let rhs = match lhs.result() {
+                    true => None,
+                    false => Some(Box::new(rhs.explanation(variables, registry)?)),
+                };
+                Explanation::Or(Box::new(lhs), rhs)
+            }
+        })
+    }
+}
+
+impl Quantified {
+    fn explanation<V: Variables + ?Sized>(
+        &self,
+        variables: &V,
+        registry: &Registry,
+    ) -> Result<QuantifiedExplanation, EvalError> {
+        let mut comparisons = Vec::new();
+        let result = self.evaluate_recorded(variables, registry, |operand, value, satisfied| {
+            comparisons.push(ComparisonExplanation {
+                operand: operand.clone(),
+                value,
+                satisfied,
+            })
+        })?;
+        Ok(QuantifiedExplanation {
+            quantifier: self.quantifier,
+            result,
+            comparisons,
+        })
+    }
+}
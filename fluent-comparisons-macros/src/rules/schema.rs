@@ -0,0 +1,157 @@
+//! Versioning of the serialized rules and the checks that deserialization shares with parsing.
+
+use super::{Comparison, Operand, ParseErrorKind, Quantified, Quantifier, Range};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// The version of the schema in which [Versioned] stores rules and reports. It is incremented whenever the
+/// serialized form of one of the types in the [rules](super) module changes.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Stores a rule, a report or any other serializable type together with the [SCHEMA_VERSION].
+///
+/// It is serialized as a struct with the fields `version` and `content`, e.g. `{"version":1,"content":...}` in JSON.
+/// Deserialization fails for any version other than the current one, so documents from a different version of this crate
+/// are rejected instead of being misinterpreted.
+///
+/// ```
+/// # use fluent_comparisons_macros::rules::{Registry, Rule, Versioned};
+/// let rule = Rule::parse("any_of({cpu} > 90)", &Registry::new()).unwrap();
+/// let json = serde_json::to_string(&Versioned(&rule)).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"version":1,"content":{"quantified":{"quantifier":"any_of","set":[{"variable":"cpu"}],"maps":[],"comparison":{"compare":[">",{"number":90.0}]}}}}"#
+/// );
+/// let Versioned(parsed) = serde_json::from_str::<Versioned<Rule>>(&json).unwrap();
+/// assert_eq!(parsed, rule);
+/// assert!(serde_json::from_str::<Versioned<Rule>>(&json.replace(r#""version":1"#, r#""version":2"#)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Versioned<T>(pub T);
+
+impl<T: Serialize> Serialize for Versioned<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Versioned", 2)?;
+        state.serialize_field("version", &SCHEMA_VERSION)?;
+        state.serialize_field("content", &self.0)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Versioned<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(
+            "Versioned",
+            &["version", "content"],
+            VersionedVisitor(PhantomData),
+        )
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Version,
+    Content,
+}
+
+/// Check the version before the content, as long as the version comes first.
+struct VersionedVisitor<T>(PhantomData<T>);
+
+fn check_version<E: de::Error>(version: u32) -> Result<(), E> {
+    if version == SCHEMA_VERSION {
+        Ok(())
+    } else {
+        Err(E::custom(format_args!(
+            "unsupported schema version {}, expected version {}",
+            version, SCHEMA_VERSION
+        )))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for VersionedVisitor<T> {
+    type Value = Versioned<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a struct with the fields `version` and `content`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        check_version(version)?;
+        let content = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(Versioned(content))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut version, mut content) = (None, None);
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Version if version.is_some() => {
+                    return Err(de::Error::duplicate_field("version"))
+                }
+                Field::Version => {
+                    let value = map.next_value()?;
+                    check_version(value)?;
+                    version = Some(value);
+                }
+                Field::Content if content.is_some() => {
+                    return Err(de::Error::duplicate_field("content"))
+                }
+                Field::Content => content = Some(map.next_value()?),
+            }
+        }
+        version.ok_or_else(|| de::Error::missing_field("version"))?;
+        let content = content.ok_or_else(|| de::Error::missing_field("content"))?;
+        Ok(Versioned(content))
+    }
+}
+
+/// The fields of a [Quantified] before they are checked by [Quantified::new].
+#[derive(serde::Deserialize)]
+#[serde(rename = "Quantified")]
+pub(super) struct QuantifiedFields {
+    quantifier: Quantifier,
+    set: Vec<Operand>,
+    maps: Vec<String>,
+    comparison: Comparison,
+}
+
+impl TryFrom<QuantifiedFields> for Quantified {
+    type Error = ParseErrorKind;
+
+    fn try_from(fields: QuantifiedFields) -> Result<Self, Self::Error> {
+        Quantified::new(
+            fields.quantifier,
+            fields.set,
+            fields.maps,
+            fields.comparison,
+        )
+    }
+}
+
+/// The fields of a [Range] before they are checked by [Range::new].
+#[derive(serde::Deserialize)]
+#[serde(rename = "Range")]
+pub(super) struct RangeFields {
+    start: Option<Operand>,
+    end: Option<Operand>,
+    inclusive: bool,
+}
+
+impl TryFrom<RangeFields> for Range {
+    type Error = ParseErrorKind;
+
+    fn try_from(fields: RangeFields) -> Result<Self, Self::Error> {
+        Range::new(fields.start, fields.end, fields.inclusive)
+    }
+}
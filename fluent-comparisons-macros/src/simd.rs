@@ -0,0 +1,207 @@
+//! Quantifiers for homogeneous sets of numbers that compare all values at once.
+//!
+//! For checks like `all_of!({lane1,lane2,/*...*/,lane8} < limit)` over primitive numbers, a single
+//! SIMD comparison followed by a reduction of the resulting mask is much faster than a chain of
+//! scalar comparisons and branches. This module provides the functions [any_of_simd], [all_of_simd]
+//! and [none_of_simd], which take a slice of numbers and compare all of them with a right hand side:
+//!
+//! ```
+//! # use fluent_comparisons_macros::simd::{all_of_simd,any_of_simd};
+//! let lanes = [1.0_f32, 2., 3., 4., 5., 6., 7., 8.];
+//! assert!(all_of_simd(&lanes).lt(10.));
+//! assert!(any_of_simd(&lanes).eq(4.));
+//! ```
+//!
+//! The macros offer the same for literal sets using the `simd` prefix, e.g. `all_of!(simd {a,b,c,d} < x)`.
+//!
+//! By default, the comparisons are implemented as a branch-free loop that the compiler can
+//! auto-vectorize. With the `simd` feature, which requires a nightly compiler, the comparisons use
+//! the portable SIMD types of `core::simd` explicitly. Either way, the results are the same as for the
+//! scalar macros. In contrast to those, all values are always compared.
+
+#[cfg(feature = "simd")]
+use core::simd::prelude::*;
+
+/// The number of lanes of the SIMD vectors that the values are compared in.
+#[cfg(feature = "simd")]
+const LANES: usize = 8;
+
+/// The comparison operators of the SIMD quantifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+/// Primitive numbers that can be compared with the SIMD quantifiers.
+///
+/// This is implemented for all primitive integer and floating point types except for the 128 bit
+/// integers.
+pub trait SimdCompare: Copy + PartialOrd {
+    /// Decide whether the comparison with the right hand side holds for any of the values.
+    fn any(values: &[Self], operator: Operator, rhs: Self) -> bool;
+    /// Decide whether the comparison with the right hand side holds for all of the values.
+    fn all(values: &[Self], operator: Operator, rhs: Self) -> bool;
+}
+
+/// Reduce the comparisons of all values with the right hand side to a single boolean
+/// using the given non short-circuiting operator (`|` or `&`) without branching.
+#[cfg(not(feature = "simd"))]
+macro_rules! reduce {
+    ($t:ty, $values:expr, $rhs:expr, $operator:tt, $simd_method:ident, $combine:tt, $reduce_mask:ident, $initial:literal) => {
+        $values
+            .iter()
+            .fold($initial, |acc, value| acc $combine (*value $operator $rhs))
+    };
+}
+
+/// Reduce the comparisons of all values with the right hand side to a single boolean
+/// using the given non short-circuiting operator (`|` or `&`). Full chunks of values are compared using SIMD
+/// vectors, while the remaining values are compared one by one.
+#[cfg(feature = "simd")]
+macro_rules! reduce {
+    ($t:ty, $values:expr, $rhs:expr, $operator:tt, $simd_method:ident, $combine:tt, $reduce_mask:ident, $initial:literal) => {{
+        let chunks = $values.chunks_exact(LANES);
+        let remainder = chunks.remainder();
+        let rhs_vector = Simd::<$t, LANES>::splat($rhs);
+        let chunks_result = chunks.fold($initial, |acc, chunk| {
+            acc $combine Simd::<$t, LANES>::from_slice(chunk)
+                .$simd_method(rhs_vector)
+                .$reduce_mask()
+        });
+        remainder
+            .iter()
+            .fold(chunks_result, |acc, value| acc $combine (*value $operator $rhs))
+    }};
+}
+
+/// Dispatch the operator to the reduction, so that the operator is matched only once and not
+/// for every value.
+macro_rules! reduce_with_operator {
+    ($t:ty, $values:expr, $operator:expr, $rhs:expr, $combine:tt, $reduce_mask:ident, $initial:literal) => {
+        match $operator {
+            Operator::Eq => reduce!($t, $values, $rhs, ==, simd_eq, $combine, $reduce_mask, $initial),
+            Operator::Ne => reduce!($t, $values, $rhs, !=, simd_ne, $combine, $reduce_mask, $initial),
+            Operator::Lt => reduce!($t, $values, $rhs, <, simd_lt, $combine, $reduce_mask, $initial),
+            Operator::Le => reduce!($t, $values, $rhs, <=, simd_le, $combine, $reduce_mask, $initial),
+            Operator::Gt => reduce!($t, $values, $rhs, >, simd_gt, $combine, $reduce_mask, $initial),
+            Operator::Ge => reduce!($t, $values, $rhs, >=, simd_ge, $combine, $reduce_mask, $initial),
+        }
+    };
+}
+
+macro_rules! impl_simd_compare {
+    ($($t:ty),+) => {
+        $(
+            impl SimdCompare for $t {
+                #[inline]
+                fn any(values: &[Self], operator: Operator, rhs: Self) -> bool {
+                    reduce_with_operator!($t, values, operator, rhs, |, any, false)
+                }
+
+                #[inline]
+                fn all(values: &[Self], operator: Operator, rhs: Self) -> bool {
+                    reduce_with_operator!($t, values, operator, rhs, &, all, true)
+                }
+            }
+        )+
+    };
+}
+
+impl_simd_compare!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+macro_rules! impl_quantifier {
+    ($(#[$meta:meta])* $name:ident, $function:ident, $reduce:ident, $negate:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<'a, T> {
+            values: &'a [T],
+        }
+
+        // the methods are named after the operators like the methods of PartialEq and PartialOrd
+        #[allow(clippy::should_implement_trait)]
+        impl<T: SimdCompare> $name<'_, T> {
+            #[inline]
+            fn compare(self, operator: Operator, rhs: T) -> bool {
+                T::$reduce(self.values, operator, rhs) ^ $negate
+            }
+
+            /// Compare the values with the right hand side using `==`.
+            #[inline]
+            pub fn eq(self, rhs: T) -> bool {
+                self.compare(Operator::Eq, rhs)
+            }
+
+            /// Compare the values with the right hand side using `!=`.
+            #[inline]
+            pub fn ne(self, rhs: T) -> bool {
+                self.compare(Operator::Ne, rhs)
+            }
+
+            /// Compare the values with the right hand side using `<`.
+            #[inline]
+            pub fn lt(self, rhs: T) -> bool {
+                self.compare(Operator::Lt, rhs)
+            }
+
+            /// Compare the values with the right hand side using `<=`.
+            #[inline]
+            pub fn le(self, rhs: T) -> bool {
+                self.compare(Operator::Le, rhs)
+            }
+
+            /// Compare the values with the right hand side using `>`.
+            #[inline]
+            pub fn gt(self, rhs: T) -> bool {
+                self.compare(Operator::Gt, rhs)
+            }
+
+            /// Compare the values with the right hand side using `>=`.
+            #[inline]
+            pub fn ge(self, rhs: T) -> bool {
+                self.compare(Operator::Ge, rhs)
+            }
+        }
+
+        /// Compare all values in the slice to a common right hand side using the methods of the
+        #[doc = concat!("returned [", stringify!($name), "].")]
+        #[inline]
+        pub fn $function<T: SimdCompare>(values: &[T]) -> $name<'_, T> {
+            $name { values }
+        }
+    };
+}
+
+impl_quantifier!(
+    /// Decides whether a comparison holds for *any of the values*. Use [any_of_simd] to create it.
+    AnyOfSimd,
+    any_of_simd,
+    any,
+    false
+);
+
+impl_quantifier!(
+    /// Decides whether a comparison holds for *all of the values*. Use [all_of_simd] to create it.
+    AllOfSimd,
+    all_of_simd,
+    all,
+    false
+);
+
+impl_quantifier!(
+    /// Decides whether a comparison holds for *none of the values*. Use [none_of_simd] to create it.
+    NoneOfSimd,
+    none_of_simd,
+    any,
+    true
+);
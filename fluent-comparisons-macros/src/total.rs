@@ -0,0 +1,81 @@
+//! Support types for the `.total()` modifier, which compares values with respect to a [TotalOrder], e.g.
+//! [f64::total_cmp] for floating point numbers, so that `NaN` is ordered, too.
+
+use core::cmp::Ordering;
+
+/// A total order on the values of a type, which the `.total()` modifier uses to compare the values.
+pub trait TotalOrder {
+    /// Compare two values with respect to the total order.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl TotalOrder for f32 {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+}
+
+impl TotalOrder for f64 {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
+
+macro_rules! impl_total_order_via_ord {
+    ($($t:ty),+) => {
+        $(
+            impl TotalOrder for $t {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_total_order_via_ord!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char, str
+);
+
+impl<T: TotalOrder + ?Sized> TotalOrder for &T {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        T::total_cmp(*self, *other)
+    }
+}
+
+/// A wrapper that implements the comparison traits using the [TotalOrder] of the wrapped value.
+/// ```
+/// # use fluent_comparisons_macros::total::Total;
+/// assert!(Total(f64::NAN) == Total(f64::NAN));
+/// assert!(Total(-0.0_f64) < Total(0.0_f64));
+/// assert!(Total(f64::INFINITY) < Total(f64::NAN));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Total<T>(pub T);
+
+impl<T: TotalOrder> PartialEq for Total<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrder> Eq for Total<T> {}
+
+impl<T: TotalOrder> PartialOrd for Total<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder> Ord for Total<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
@@ -0,0 +1,123 @@
+//! Support traits for sets of values that are only known at runtime.
+//!
+//! With the set placeholder, `all_of!({_} < limit)` evaluates to a closure that takes the set of values
+//! as its argument, e.g. an array or a tuple. The closure quantifies over the values of the argument using the
+//! [Values] trait and the [any_of], [all_of] and [none_of] functions in this module. The same goes for the spread
+//! syntax `all_of!({..values} < limit)`, which quantifies over anything that implements [IntoIterator] by
+//! wrapping its iterator in [Spread].
+
+/// A set of values of the same type that the macros can quantify over.
+///
+/// This is implemented for arrays, slices and tuples of up to twelve values of the same type.
+/// For arrays and tuples the values are moved, for slices and references to arrays the values are references.
+pub trait Values {
+    /// The type of the values in the set.
+    type Value;
+    /// The iterator over the values.
+    type IntoValues: Iterator<Item = Self::Value>;
+
+    /// Obtain an iterator over the values from left to right.
+    fn into_values(self) -> Self::IntoValues;
+}
+
+impl<T, const N: usize> Values for [T; N] {
+    type Value = T;
+    type IntoValues = core::array::IntoIter<T, N>;
+
+    #[inline]
+    fn into_values(self) -> Self::IntoValues {
+        IntoIterator::into_iter(self)
+    }
+}
+
+impl<'a, T, const N: usize> Values for &'a [T; N] {
+    type Value = &'a T;
+    type IntoValues = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_values(self) -> Self::IntoValues {
+        self.iter()
+    }
+}
+
+impl<'a, T> Values for &'a [T] {
+    type Value = &'a T;
+    type IntoValues = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_values(self) -> Self::IntoValues {
+        self.iter()
+    }
+}
+
+/// The type of every element of the tuples, independent of the name of the element.
+macro_rules! element_type {
+    ($element:ident) => {
+        T
+    };
+}
+
+/// Implement [Values] for tuples of values of the same type by moving the elements into an array.
+macro_rules! impl_values_for_tuples {
+    ($(($count:literal; $($element:ident),+)),+) => {
+        $(
+            impl<T> Values for ($(element_type!($element),)+) {
+                type Value = T;
+                type IntoValues = core::array::IntoIter<T, $count>;
+
+                #[inline]
+                fn into_values(self) -> Self::IntoValues {
+                    let ($($element,)+) = self;
+                    IntoIterator::into_iter([$($element),+])
+                }
+            }
+        )+
+    };
+}
+
+impl_values_for_tuples!(
+    (1; a),
+    (2; a, b),
+    (3; a, b, c),
+    (4; a, b, c, d),
+    (5; a, b, c, d, e),
+    (6; a, b, c, d, e, f),
+    (7; a, b, c, d, e, f, g),
+    (8; a, b, c, d, e, f, g, h),
+    (9; a, b, c, d, e, f, g, h, i),
+    (10; a, b, c, d, e, f, g, h, i, j),
+    (11; a, b, c, d, e, f, g, h, i, j, k),
+    (12; a, b, c, d, e, f, g, h, i, j, k, l)
+);
+
+/// The values of an iterator, which the spread syntax `{..values}` of the macros quantifies over.
+#[derive(Debug, Clone)]
+pub struct Spread<I>(pub I);
+
+impl<I: Iterator> Values for Spread<I> {
+    type Value = I::Item;
+    type IntoValues = I;
+
+    #[inline]
+    fn into_values(self) -> Self::IntoValues {
+        self.0
+    }
+}
+
+/// Decide whether the predicate holds for any of the values, evaluating it lazily from left to right.
+#[inline]
+pub fn any_of<V: Values>(values: V, predicate: impl FnMut(V::Value) -> bool) -> bool {
+    values.into_values().any(predicate)
+}
+
+/// Decide whether the predicate holds for all of the values, evaluating it lazily from left to right.
+#[inline]
+pub fn all_of<V: Values>(values: V, predicate: impl FnMut(V::Value) -> bool) -> bool {
+    values.into_values().all(predicate)
+}
+
+/// Decide whether the predicate holds for none of the values, evaluating it lazily from left to right.
+#[inline]
+pub fn none_of<V: Values>(values: V, predicate: impl FnMut(V::Value) -> bool) -> bool {
+    !values.into_values().any(predicate)
+}
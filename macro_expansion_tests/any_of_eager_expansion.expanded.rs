@@ -0,0 +1,10 @@
+use fluent_comparisons::any_of;
+pub fn something(a: i32, b: i32, c: i32, d: i32) {
+    let first = {
+        (a < 4) | (b < 4) | (c < 4) | (d < 4)
+    };
+    let second = {
+        let map_func = |x| x % 2;
+        (map_func(a) == 0) | (map_func(b) == 0) | (map_func(c) == 0) | (map_func(d) == 0)
+    };
+}
@@ -0,0 +1,6 @@
+use fluent_comparisons::any_of;
+
+pub fn something(a: i32, b: i32, c: i32, d: i32) {
+    let first = any_of!(eager {a,b,c,d} < 4);
+    let second = any_of!(eager {a,b,c,d}.map(|x|x%2) == 0);
+}
\ No newline at end of file
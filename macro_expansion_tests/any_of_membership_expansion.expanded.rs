@@ -0,0 +1,47 @@
+use fluent_comparisons::any_of;
+pub fn something(code: u16, method: &str) {
+    let first = match code {
+        value => {
+            if ::fluent_comparisons_macros::membership::is_str(&value) {
+                ::fluent_comparisons_macros::membership::contains(
+                    &const {
+                        ::fluent_comparisons_macros::membership::SortedStrs::from_literals([
+                            200, 204, 301, 304, 404,
+                        ])
+                    },
+                    value,
+                )
+            } else {
+                #[allow(unreachable_patterns)]
+                let matched = #[allow(non_exhaustive_omitted_patterns)]
+                match value {
+                    200 | 204 | 301 | 304 | 404 => true,
+                    _ => false,
+                };
+                matched
+            }
+        }
+    };
+    let second = match method {
+        value => {
+            if ::fluent_comparisons_macros::membership::is_str(&value) {
+                ::fluent_comparisons_macros::membership::contains(
+                    &const {
+                        ::fluent_comparisons_macros::membership::SortedStrs::from_literals([
+                            "PUT", "GET", "HEAD",
+                        ])
+                    },
+                    value,
+                )
+            } else {
+                #[allow(unreachable_patterns)]
+                let matched = #[allow(non_exhaustive_omitted_patterns)]
+                match value {
+                    "PUT" | "GET" | "HEAD" => true,
+                    _ => false,
+                };
+                matched
+            }
+        }
+    };
+}
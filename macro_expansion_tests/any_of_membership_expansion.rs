@@ -0,0 +1,6 @@
+use fluent_comparisons::any_of;
+
+pub fn something(code: u16, method: &str) {
+    let first = any_of!(code == {200, 204, 301, 304, 404});
+    let second = any_of!(method == {"PUT", "GET", "HEAD"});
+}
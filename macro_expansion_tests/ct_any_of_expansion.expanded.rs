@@ -0,0 +1,21 @@
+use fluent_comparisons::ct_any_of;
+pub fn something(tag: [u8; 4], key1: [u8; 4], key2: [u8; 4], level: u32) {
+    let first = {
+        ({
+            use ::fluent_comparisons_macros::constant_time::subtle::ConstantTimeEq as _;
+            key1.ct_eq(&tag)
+        }) | {
+            use ::fluent_comparisons_macros::constant_time::subtle::ConstantTimeEq as _;
+            key2.ct_eq(&tag)
+        }
+    };
+    let second = {
+        ({
+            use ::fluent_comparisons_macros::constant_time::subtle::ConstantTimeGreater as _;
+            level.ct_gt(&3)
+        }) | {
+            use ::fluent_comparisons_macros::constant_time::subtle::ConstantTimeGreater as _;
+            (level + 1).ct_gt(&3)
+        }
+    };
+}
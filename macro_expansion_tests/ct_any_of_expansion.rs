@@ -0,0 +1,6 @@
+use fluent_comparisons::ct_any_of;
+
+pub fn something(tag: [u8; 4], key1: [u8; 4], key2: [u8; 4], level: u32) {
+    let first = ct_any_of!({key1,key2} == tag);
+    let second = ct_any_of!({level,level+1} > 3);
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use fluent_comparisons::try_any_of;
+pub fn something() {
+    let parse = |s: &str| s.parse::<i32>();
+    let cond1 =
+        {
+            let map_func = parse;
+            'fallible: {
+                match ::fluent_comparisons_macros::lift::Lift::lift(map_func("1")) {
+                    ::core::result::Result::Ok(value) => {
+                        if (value > 1) {
+                            break 'fallible
+                                ::fluent_comparisons_macros::lift::LiftOutput::from_bool(true);
+                        }
+                    }
+                    ::core::result::Result::Err(output) => break 'fallible output,
+                }
+                match ::fluent_comparisons_macros::lift::Lift::lift(map_func("2")) {
+                    ::core::result::Result::Ok(value) => {
+                        if (value > 1) {
+                            break 'fallible
+                                ::fluent_comparisons_macros::lift::LiftOutput::from_bool(true);
+                        }
+                    }
+                    ::core::result::Result::Err(output) => break 'fallible output,
+                }
+                ::fluent_comparisons_macros::lift::LiftOutput::from_bool(false)
+            }
+        };
+    let is_even = |s: &str| parse(s).map(|x| x % 2 == 0);
+    let cond2 =
+        {
+            let map_func = is_even;
+            'fallible: {
+                match ::fluent_comparisons_macros::lift::Lift::lift(map_func("1")) {
+                    ::core::result::Result::Ok(value) => {
+                        if (value == true) {
+                            break 'fallible
+                                ::fluent_comparisons_macros::lift::LiftOutput::from_bool(true);
+                        }
+                    }
+                    ::core::result::Result::Err(output) => break 'fallible output,
+                }
+                match ::fluent_comparisons_macros::lift::Lift::lift(map_func("2")) {
+                    ::core::result::Result::Ok(value) => {
+                        if (value == true) {
+                            break 'fallible
+                                ::fluent_comparisons_macros::lift::LiftOutput::from_bool(true);
+                        }
+                    }
+                    ::core::result::Result::Err(output) => break 'fallible output,
+                }
+                ::fluent_comparisons_macros::lift::LiftOutput::from_bool(false)
+            }
+        };
+}
@@ -0,0 +1,8 @@
+use fluent_comparisons::try_any_of;
+
+pub fn something() {
+    let parse = |s: &str| s.parse::<i32>();
+    let cond1 = try_any_of!({"1","2"}.map(parse) > 1);
+    let is_even = |s: &str| parse(s).map(|x| x % 2 == 0);
+    let cond2 = try_any_of!({"1","2"}.satisfy(is_even));
+}
\ No newline at end of file
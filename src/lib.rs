@@ -53,6 +53,105 @@
 //! # }
 //! ```
 //!
+//! For floating point numbers, where `NaN` makes the comparison operators a partial order only,
+//! the macros offer a `.total()` modifier that compares with respect to the [total order](total::TotalOrder)
+//! and a `.strict()` modifier that reports unordered comparisons instead of silently evaluating them to `false`.
+//!
+//! ```
+//! # use fluent_comparisons::{all_of,none_of};
+//! let nan = f64::NAN;
+//! assert!(none_of!({1.,nan}>2.));
+//! assert!(!none_of!({1.,nan}.total()>2.));
+//! assert_eq!(all_of!({1.,nan}.strict()<2.), None);
+//! ```
+//!
+//! With the placeholder `_` in place of the right hand side or the set, the macros evaluate to a reusable closure.
+//!
+//! ```
+//! # use fluent_comparisons::{any_of,all_of};
+//! let (a, b) = (1, 5);
+//! let exceeded: Vec<i32> = vec![0, 3, 7].into_iter().filter(any_of!({a,b} > *_)).collect();
+//! assert_eq!(exceeded, [0, 3]);
+//! assert!(all_of!({_} < 10)([a, b]));
+//! ```
+//!
+//! Values that are only known at runtime are spread into the set with `{..values}`. With the `derive` feature,
+//! `#[derive(Quantify)]` lets the macros spread the fields of a struct, so that new fields are picked up automatically.
+//!
+//! ```
+//! # use fluent_comparisons::all_of;
+//! let limits = vec![10, 20, 30];
+//! assert!(all_of!({..&limits} > &5));
+//! ```
+//!
+//! If the transformation can fail, the macros `try_any_of`, `try_all_of` and `try_none_of` propagate the first
+//! error and otherwise evaluate to `Ok(result)`.
+//!
+//! ```
+//! # use fluent_comparisons::try_all_of;
+//! assert_eq!(try_all_of!({"1","2"}.map(str::parse::<i32>) < 3), Ok(true));
+//! assert!(try_all_of!({"1","two"}.map(str::parse::<i32>) < 3).is_err());
+//! ```
+//!
+//! To compare the values in a set with each other rather than with a common right hand side, use `all_equal` and
+//! `all_distinct`, which perform the minimal number of pairwise comparisons without allocating, or check the order of the
+//! values with `is_sorted`, `is_strictly_increasing` and `is_strictly_decreasing`.
+//!
+//! ```
+//! # use fluent_comparisons::{all_equal,all_distinct};
+//! let (a, b, c) = ("one", "two", "six");
+//! assert!(all_distinct!({a,b,c}));
+//! assert!(all_equal!({a,b,c}.by_key(str::len)));
+//! ```
+//!
+//! Chains of comparisons like in mathematical notation are written with `cmp_chain`, where each operand is evaluated
+//! at most once. Operands can be quantified sets of values, too.
+//!
+//! ```
+//! # use fluent_comparisons::cmp_chain;
+//! let (x, y, limit) = (3, 5, 10);
+//! assert!(cmp_chain!(0 <= x < y <= limit));
+//! assert!(cmp_chain!(0 <= all{x,y} < limit));
+//! ```
+//!
+//! The macros `min_of`, `max_of` and `minmax_of` find the extrema of a set of values with the same syntax, and `argmin_of`
+//! and `argmax_of` find their positions. They work for floating point numbers, too, where `NaN` values are skipped.
+//!
+//! ```
+//! # use fluent_comparisons::{min_of,argmax_of};
+//! assert_eq!(min_of!({2.5,f64::NAN,-1.}), -1.);
+//! assert_eq!(argmax_of!({"three","one","four"}.map(str::len)), 0);
+//! ```
+//!
+//! The macros can also be evaluated in const contexts, and `const_assert_all_of`, `const_assert_any_of` and
+//! `const_assert_none_of` fail the build with a message naming the offending element.
+//!
+//! ```
+//! # use fluent_comparisons::{any_of,const_assert_all_of};
+//! const LIMIT: u32 = 10;
+//! const fn increment(x: u32) -> u32 { x + 1 }
+//! const OK: bool = any_of!({1,5,12}.map(increment) > LIMIT);
+//! const_assert_all_of!({1,5,7} < LIMIT);
+//! ```
+//!
+//! In asynchronous code, the macros `any_of_async`, `all_of_async` and `none_of_async` accept a set of futures
+//! which they `.await` lazily one at a time from left to right. The macros `any_of_concurrent`, `all_of_concurrent`
+//! and `none_of_concurrent` poll all futures together instead and resolve as soon as the result is known.
+//!
+//! With the `rayon` feature, the macros `par_any_of`, `par_all_of` and `par_none_of` evaluate expensive comparisons
+//! in parallel. Note that they do not evaluate the values from left to right.
+//!
+//! With the `constant-time` feature, the macros `ct_any_of`, `ct_all_of` and `ct_none_of` compare secret data
+//! without branching on the results of the comparisons and evaluate to a `subtle::Choice`.
+//!
+//! With the `regex` and `glob` features, `any_of!({...}.matches_regex(&re))` and `none_of!({...}.matches_glob("*.rs"))` match the values
+//! against a regular expression or a glob pattern, which is compiled at most once per invocation. For patterns given as strings,
+//! they evaluate to a `Result` that holds the error if the pattern is invalid.
+//!
+//! With the `rules` feature, the `rules` module parses conditions like `"any_of({cpu, mem} > 90) && none_of({disk} < 5)"`
+//! from text and evaluates them at runtime against variables that are looked up by name, with the same lazy semantics as the macros.
+//! With the `serde` feature, the rules and the reports that explain their results implement `Serialize` and `Deserialize`.
+//!
 //! And finally, you can rest assured in the warm and fuzzy feeling that this crate is excessively tested.
 //!
 //! ## Usage
@@ -65,6 +164,105 @@ pub use fluent_comparisons_macros::all_of;
 
 pub use fluent_comparisons_macros::none_of;
 
+pub use fluent_comparisons_macros::all_equal;
+
+pub use fluent_comparisons_macros::all_distinct;
+
+pub use fluent_comparisons_macros::is_sorted;
+
+pub use fluent_comparisons_macros::is_strictly_increasing;
+
+pub use fluent_comparisons_macros::is_strictly_decreasing;
+
+pub use fluent_comparisons_macros::cmp_chain;
+
+pub use fluent_comparisons_macros::min_of;
+
+pub use fluent_comparisons_macros::max_of;
+
+pub use fluent_comparisons_macros::minmax_of;
+
+pub use fluent_comparisons_macros::argmin_of;
+
+pub use fluent_comparisons_macros::argmax_of;
+
+#[cfg(feature = "derive")]
+pub use fluent_comparisons_macros::Quantify;
+
+pub use fluent_comparisons_macros::const_assert_all_of;
+
+pub use fluent_comparisons_macros::const_assert_any_of;
+
+pub use fluent_comparisons_macros::const_assert_none_of;
+
+pub use fluent_comparisons_macros::try_any_of;
+
+pub use fluent_comparisons_macros::try_all_of;
+
+pub use fluent_comparisons_macros::try_none_of;
+
+pub use fluent_comparisons_macros::any_of_async;
+
+pub use fluent_comparisons_macros::all_of_async;
+
+pub use fluent_comparisons_macros::none_of_async;
+
+pub use fluent_comparisons_macros::any_of_concurrent;
+
+pub use fluent_comparisons_macros::all_of_concurrent;
+
+pub use fluent_comparisons_macros::none_of_concurrent;
+
+#[cfg(feature = "rayon")]
+pub use fluent_comparisons_macros::par_any_of;
+
+#[cfg(feature = "rayon")]
+pub use fluent_comparisons_macros::par_all_of;
+
+#[cfg(feature = "rayon")]
+pub use fluent_comparisons_macros::par_none_of;
+
+#[cfg(feature = "constant-time")]
+pub use fluent_comparisons_macros::ct_any_of;
+
+#[cfg(feature = "constant-time")]
+pub use fluent_comparisons_macros::ct_all_of;
+
+#[cfg(feature = "constant-time")]
+pub use fluent_comparisons_macros::ct_none_of;
+
+pub use fluent_comparisons_macros::borrow;
+
+pub use fluent_comparisons_macros::by;
+
+pub use fluent_comparisons_macros::concurrent;
+
+#[cfg(feature = "constant-time")]
+pub use fluent_comparisons_macros::constant_time;
+
+pub use fluent_comparisons_macros::extrema;
+
+#[cfg(feature = "rayon")]
+pub use fluent_comparisons_macros::parallel;
+
+pub use fluent_comparisons_macros::lift;
+
+pub use fluent_comparisons_macros::membership;
+
+#[cfg(any(feature = "regex", feature = "glob"))]
+pub use fluent_comparisons_macros::pattern;
+
+pub use fluent_comparisons_macros::pipeline;
+
+#[cfg(feature = "rules")]
+pub use fluent_comparisons_macros::rules;
+
+pub use fluent_comparisons_macros::simd;
+
+pub use fluent_comparisons_macros::total;
+
+pub use fluent_comparisons_macros::values;
+
 #[cfg(test)]
 #[allow(clippy::bool_assert_comparison)]
 mod tests;
@@ -0,0 +1,102 @@
+use super::helper::{block_on, CountedFuture};
+use crate::all_of_async;
+use crate::any_of_async;
+use crate::none_of_async;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+// helper function that asynchronously doubles a value
+async fn twice(val: i32) -> i32 {
+    2 * val
+}
+
+#[test]
+fn async_quantifiers_give_correct_results() {
+    block_on(async {
+        assert!(any_of_async!({twice(1),twice(2)} == 4));
+        assert!(!any_of_async!({twice(1),twice(2)} == 3));
+        assert!(all_of_async!({twice(1),twice(2)} <= 4));
+        assert!(!all_of_async!({twice(1),twice(2)} < 4));
+        assert!(none_of_async!({twice(1),twice(2)} > 4));
+        assert!(!none_of_async!({twice(1),twice(2)} >= 4));
+
+        // with map and satisfy
+        assert!(any_of_async!({twice(1),twice(2)}.map(|x|x*x) == 16));
+        assert!(all_of_async!({twice(1),twice(2)}.satisfy(|x|x%2==0)));
+        assert!(none_of_async!({twice(1),twice(2)}.map(|x|x+1) == 4));
+
+        // with futures that are pending before they resolve
+        let poll_count = Cell::new(0);
+        assert!(
+            all_of_async!({CountedFuture::new(1,2,&poll_count),CountedFuture::new(2,3,&poll_count)} > 0)
+        );
+        assert_eq!(poll_count.get(), 7);
+    });
+}
+
+#[test]
+fn async_quantifiers_await_lazily_from_left_to_right() {
+    block_on(async {
+        let first = Cell::new(0);
+        let second = Cell::new(0);
+        let third = Cell::new(0);
+        assert!(any_of_async!({
+            CountedFuture::new(1, 1, &first),
+            CountedFuture::new(2, 1, &second),
+            CountedFuture::new(3, 1, &third)
+        } == 2));
+        assert_eq!((first.get(), second.get(), third.get()), (2, 2, 0));
+
+        assert!(!all_of_async!({
+            CountedFuture::new(1, 0, &first),
+            CountedFuture::new(2, 0, &second),
+            CountedFuture::new(3, 0, &third)
+        } > 1));
+        assert_eq!((first.get(), second.get(), third.get()), (3, 2, 0));
+
+        assert!(!none_of_async!({
+            CountedFuture::new(1, 0, &first),
+            CountedFuture::new(2, 0, &second),
+            CountedFuture::new(3, 0, &third)
+        }.map(|x|x*2) == 2));
+        assert_eq!((first.get(), second.get(), third.get()), (4, 2, 0));
+
+        // the remaining expressions are not even evaluated
+        let created = Cell::new(0);
+        let create = |value: i32| {
+            created.set(created.get() + 1);
+            twice(value)
+        };
+        assert!(any_of_async!({create(1),create(2)} == 2));
+        assert_eq!(created.get(), 1);
+    });
+}
+
+#[test]
+// compare the results against a solution using standard library iterators
+fn test_random_collection_of_futures_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..10000 {
+        let a = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-10..10);
+
+        block_on(async {
+            assert_all_eq!(
+                any_of_async!({twice(a),twice(b),twice(c)} < rhs),
+                [a, b, c].iter().any(|v| 2 * v < rhs)
+            );
+            assert_all_eq!(
+                all_of_async!({twice(a),twice(b),twice(c)}.map(|x|x+1) >= rhs),
+                [a, b, c].iter().all(|v| 2 * v + 1 >= rhs)
+            );
+            assert_all_eq!(
+                none_of_async!({twice(a),twice(b),twice(c)} == rhs),
+                ![a, b, c].iter().any(|v| 2 * v == rhs)
+            );
+        });
+    }
+}
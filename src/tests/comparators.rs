@@ -0,0 +1,110 @@
+use crate::all_of;
+use crate::any_of;
+use crate::by::ignore_ascii_case;
+use crate::none_of;
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use rand::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl Version {
+    fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+fn version_cmp(lhs: &Version, rhs: &Version) -> Ordering {
+    (lhs.major, lhs.minor).cmp(&(rhs.major, rhs.minor))
+}
+
+#[test]
+fn comparisons_by_a_comparator_give_correct_results() {
+    let (p, q) = (Version::new(1, 4), Version::new(2, 0));
+    assert!(all_of!({p,q}.by(version_cmp) >= Version::new(1, 2)));
+    assert_eq!(all_of!({p,q}.by(version_cmp) > Version::new(1, 4)), false);
+    assert!(any_of!({p,q}.by(version_cmp) == Version::new(2, 0)));
+    assert!(any_of!({p,q,}.by(version_cmp) != Version::new(2, 0)));
+    assert!(none_of!({p,q}.by(version_cmp) < Version::new(1, 0)));
+    assert_eq!(none_of!({p,q}.by(version_cmp) <= Version::new(1, 4)), false);
+
+    // closures and reversed orderings
+    assert!(any_of!({p,q}.by(|lhs, rhs| lhs.minor.cmp(&rhs.minor)) == Version::new(7, 0)));
+    assert!(all_of!({3,5,7}.by(|lhs, rhs| rhs.cmp(lhs)) < 1));
+    assert!(all_of!({1.5_f64, 2.5}.by(|lhs, rhs| lhs.total_cmp(rhs)) < 3.0));
+
+    // the values are not moved
+    assert_eq!(p, Version::new(1, 4));
+    assert_eq!(q, Version::new(2, 0));
+}
+
+#[test]
+fn comparisons_by_a_key_give_correct_results() {
+    let (a, b, c) = ("Guest", "ADMIN", "root");
+    assert!(any_of!({a,b,c}.by_key(str::to_lowercase) == "admin"));
+    assert_eq!(any_of!({a,b,c}.by_key(str::to_lowercase) == "user"), false);
+    assert!(all_of!({a,b,c}.by_key(str::len) >= "four"));
+    assert!(none_of!({a,b,c}.by_key(str::to_uppercase) == "user"));
+
+    let (x, y) = (String::from("Guest"), String::from("ADMIN"));
+    assert!(any_of!({x,y}.by_key(str::to_lowercase) == "guest"));
+    assert!(all_of!({x,y}.by_key(|s| s.len()) == String::from("abcde")));
+    assert_eq!(x, "Guest");
+}
+
+#[test]
+fn ignore_ascii_case_compares_case_insensitively() {
+    assert_eq!(ignore_ascii_case("Admin", "aDMIN"), Ordering::Equal);
+    assert_eq!(ignore_ascii_case("a", "B"), Ordering::Less);
+    assert_eq!(ignore_ascii_case("Ab", "a"), Ordering::Greater);
+    assert_eq!(ignore_ascii_case("", ""), Ordering::Equal);
+    assert!(any_of!({"Guest","ADMIN"}.by(ignore_ascii_case) == "admin"));
+    assert!(all_of!({"Guest","ADMIN"}.by(ignore_ascii_case) < "Z"));
+}
+
+#[test]
+fn comparisons_by_a_comparator_or_key_are_lazy() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert!(any_of!({counted(1),counted(2),counted(3)}.by(|lhs, rhs| lhs.cmp(rhs)) == 2));
+    assert_eq!(count.get(), 2);
+    assert_eq!(
+        all_of!({counted(1),counted(2),counted(3)}.by_key(|x| x % 2) == 1),
+        false
+    );
+    assert_eq!(count.get(), 4);
+}
+
+#[test]
+// compare the results against comparisons of the keys and orderings by hand
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+
+        assert_all_eq!(
+            any_of!({a,b,c}.by_key(|x: &i32| x.abs()) < rhs),
+            any_of!({a,b,c}.map(i32::abs) < rhs.abs())
+        );
+        assert_all_eq!(
+            all_of!({a,b,c}.by(|lhs, rhs| rhs.cmp(lhs)) >= rhs),
+            all_of!({a,b,c} <= rhs)
+        );
+        assert_all_eq!(
+            none_of!({a,b,c}.by(i32::cmp) != rhs),
+            none_of!({a,b,c} != rhs)
+        );
+    }
+}
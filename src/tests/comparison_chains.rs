@@ -0,0 +1,88 @@
+use crate::all_of;
+use crate::any_of;
+use crate::cmp_chain;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn comparison_chains_give_correct_results() {
+    let (x, y, limit) = (3, 5, 10);
+    assert!(cmp_chain!(0 <= x < y <= limit));
+    assert_eq!(cmp_chain!(0 <= y < x <= limit), false);
+    assert_eq!(cmp_chain!(0 <= x < y <= 4), false);
+    assert!(cmp_chain!(x < y));
+    assert!(cmp_chain!(x != y == 5 > -1 >= -1));
+    assert!(cmp_chain!(x + 2 == y));
+    assert!(cmp_chain!(x as i64 > -1));
+    assert!(cmp_chain!(0 < (std::mem::size_of::<u32>()) < 8));
+    assert!(cmp_chain!("a" < "b" == String::from("b")));
+    assert_eq!(cmp_chain!(1. < f64::NAN < 2.), false);
+    assert_eq!(cmp_chain!(1. < f64::NAN != 2.), false);
+}
+
+#[test]
+fn comparison_chains_with_quantified_operands_give_correct_results() {
+    let (a, b, c, limit) = (1, 4, 7, 5);
+    assert!(cmp_chain!(0 <= all{a,b} < limit));
+    assert_eq!(cmp_chain!(0 <= all{a,b,c} < limit), false);
+    assert!(cmp_chain!(0 <= all{a,b,c,} < any{limit, 10}));
+    assert!(cmp_chain!(limit < any{a,b,c} <= 7));
+    assert_eq!(cmp_chain!(limit < any{a,b} <= 7), false);
+    assert!(cmp_chain!(none { a, b, c } == limit));
+    assert_eq!(cmp_chain!(0 < none{a,b,c} < limit), false);
+    // the quantifier on the left binds first
+    assert!(cmp_chain!(any{a,c} < all{2,3}));
+    assert_eq!(cmp_chain!(all{a,c} < any{2,3}), false);
+    assert!(cmp_chain!(all{a,b} < any{2,5}));
+    assert!(cmp_chain!(none{b,c} < all{2,3}));
+}
+
+#[test]
+fn comparison_chains_evaluate_each_operand_once_and_lazily() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert!(cmp_chain!(counted(0) <= counted(1) < counted(2) <= counted(2)));
+    assert_eq!(count.get(), 4);
+    assert_eq!(
+        cmp_chain!(counted(0) <= counted(-1) < counted(2) <= counted(2)),
+        false
+    );
+    assert_eq!(count.get(), 6);
+    assert_eq!(
+        cmp_chain!(counted(0) < all{counted(1), counted(-1)} < counted(2)),
+        false
+    );
+    assert_eq!(count.get(), 9);
+    assert!(cmp_chain!(counted(0) < any{counted(1), counted(-1)} < counted(2)));
+    assert_eq!(count.get(), 13);
+}
+
+#[test]
+// compare the results against the handwritten chains of comparisons
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: i32 = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let d = rng.gen_range(-5..5);
+
+        assert_all_eq!(cmp_chain!(a <= b < c), a <= b && b < c);
+        assert_all_eq!(cmp_chain!(a == b != c >= d), a == b && b != c && c >= d);
+        assert_all_eq!(
+            cmp_chain!(a < all{b,c} <= d),
+            all_of!({b,c} > a) && all_of!({b,c} <= d)
+        );
+        assert_all_eq!(
+            cmp_chain!(a > any{b,c} != d),
+            any_of!({b,c} < a) && any_of!({b,c} != d)
+        );
+        assert_all_eq!(cmp_chain!(none { a, b } > c), none_of!({a,b} > c));
+    }
+}
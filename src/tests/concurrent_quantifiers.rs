@@ -0,0 +1,119 @@
+use super::helper::{block_on, CountedFuture};
+use crate::all_of_concurrent;
+use crate::any_of_concurrent;
+use crate::none_of_concurrent;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+// helper function that asynchronously doubles a value
+async fn twice(val: i32) -> i32 {
+    2 * val
+}
+
+#[test]
+fn concurrent_quantifiers_give_correct_results() {
+    block_on(async {
+        assert!(any_of_concurrent!({twice(1),twice(2)} == 4));
+        assert!(!any_of_concurrent!({twice(1),twice(2)} == 3));
+        assert!(all_of_concurrent!({twice(1),twice(2)} <= 4));
+        assert!(!all_of_concurrent!({twice(1),twice(2)} < 4));
+        assert!(none_of_concurrent!({twice(1),twice(2)} > 4));
+        assert!(!none_of_concurrent!({twice(1),twice(2)} >= 4));
+
+        // the third future is not polled again once the second future decided the result
+        let (first, second, third) = (Cell::new(0), Cell::new(0), Cell::new(0));
+        assert!(!none_of_concurrent!({
+            CountedFuture::new(1, 0, &first),
+            CountedFuture::new(2, 1, &second),
+            CountedFuture::new(3, 10, &third)
+        } >= 2));
+        assert_eq!((first.get(), second.get(), third.get()), (1, 2, 1));
+
+        // with map and satisfy
+        assert!(any_of_concurrent!({twice(1),twice(2)}.map(|x|x*x) == 16));
+        assert!(all_of_concurrent!({twice(1),twice(2)}.satisfy(|x|x%2==0)));
+        assert!(none_of_concurrent!({twice(1),twice(2)}.map(|x|x+1) == 4));
+    });
+}
+
+#[test]
+fn concurrent_quantifiers_resolve_on_first_decisive_result() {
+    block_on(async {
+        let slow = Cell::new(0);
+        let fast = Cell::new(0);
+        let never = Cell::new(0);
+        // the fast future decides the result, so the slow future is not polled to completion
+        assert!(any_of_concurrent!({
+            CountedFuture::new(2, 10, &slow),
+            CountedFuture::new(2, 2, &fast)
+        } == 2));
+        assert_eq!((slow.get(), fast.get()), (3, 3));
+
+        assert!(!all_of_concurrent!({
+            CountedFuture::new(1, 10, &slow),
+            CountedFuture::new(-1, 0, &fast)
+        } > 0));
+        assert_eq!((slow.get(), fast.get()), (4, 4));
+
+        assert!(!none_of_concurrent!({
+            CountedFuture::new(1, 10, &slow),
+            CountedFuture::new(1, 1, &fast),
+            CountedFuture::new(1, 10, &never)
+        }.map(|x|x*2) == 2));
+        assert_eq!((slow.get(), fast.get(), never.get()), (6, 6, 1));
+
+        // if no result is decisive, all futures are polled to completion
+        assert!(!any_of_concurrent!({
+            CountedFuture::new(1, 3, &slow),
+            CountedFuture::new(1, 1, &fast)
+        } == 2));
+        assert_eq!((slow.get(), fast.get()), (10, 8));
+    });
+}
+
+#[test]
+// compare the results against a solution using standard library iterators
+fn test_random_collection_of_futures_behave_correctly() {
+    let mut rng = thread_rng();
+    let poll_count = Cell::new(0);
+
+    for _ in 1..10000 {
+        let a = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+        let (pa, pb, pc) = (
+            rng.gen_range(0..3),
+            rng.gen_range(0..3),
+            rng.gen_range(0..3),
+        );
+
+        block_on(async {
+            assert_all_eq!(
+                any_of_concurrent!({
+                    CountedFuture::new(a, pa, &poll_count),
+                    CountedFuture::new(b, pb, &poll_count),
+                    CountedFuture::new(c, pc, &poll_count)
+                } < rhs),
+                [a, b, c].iter().any(|v| *v < rhs)
+            );
+            assert_all_eq!(
+                all_of_concurrent!({
+                    CountedFuture::new(a, pa, &poll_count),
+                    CountedFuture::new(b, pb, &poll_count),
+                    CountedFuture::new(c, pc, &poll_count)
+                }.map(|x|x+1) >= rhs),
+                [a, b, c].iter().all(|v| v + 1 >= rhs)
+            );
+            assert_all_eq!(
+                none_of_concurrent!({
+                    CountedFuture::new(a, pa, &poll_count),
+                    CountedFuture::new(b, pb, &poll_count),
+                    CountedFuture::new(c, pc, &poll_count)
+                } == rhs),
+                ![a, b, c].contains(&rhs)
+            );
+        });
+    }
+}
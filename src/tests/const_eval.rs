@@ -0,0 +1,82 @@
+use crate::all_of;
+use crate::any_of;
+use crate::const_assert_all_of;
+use crate::const_assert_any_of;
+use crate::const_assert_none_of;
+use crate::membership::SortedStrs;
+use crate::none_of;
+
+const LIMIT: i32 = 10;
+const A: i32 = 1;
+const B: i32 = 5;
+const C: i32 = 12;
+
+const fn square(x: i32) -> i32 {
+    x * x
+}
+
+const fn is_even(x: i32) -> bool {
+    x % 2 == 0
+}
+
+const fn double(x: i32) -> i32 {
+    x * 2
+}
+
+const fn exceeds_limit(x: i32) -> bool {
+    x > LIMIT
+}
+
+// a type that takes a const generic argument computed by the macros
+struct Flag<const SET: bool>;
+
+impl<const SET: bool> Flag<SET> {
+    const fn get() -> bool {
+        SET
+    }
+}
+
+const_assert_all_of!({A, B} < LIMIT);
+const_assert_all_of!({A, B, C}.map(square) > 0);
+const_assert_all_of!({A, B}.map(double) <= LIMIT);
+const_assert_any_of!({A, B, C}.satisfy(is_even));
+const_assert_any_of!({A, B, C}.satisfy(exceeds_limit));
+const_assert_none_of!({A, B, C} == 0);
+const_assert_none_of!({A, B}.satisfy(is_even));
+
+#[test]
+fn quantifiers_can_be_evaluated_in_const_contexts() {
+    const BASIC: [bool; 3] = [
+        any_of!({A, B, C} > LIMIT),
+        all_of!({A, B, C} > LIMIT),
+        none_of!({A, B, C} > LIMIT),
+    ];
+    assert_eq!(BASIC, [true, false, false]);
+
+    const MAPPED: [bool; 3] = [
+        any_of!({A, B, C}.map(square) == 25),
+        all_of!({A, B, C}.satisfy(is_even)),
+        none_of!({A, B, C}.map(square) > 1000),
+    ];
+    assert_eq!(MAPPED, [true, false, true]);
+
+    const EAGER: [bool; 3] = [
+        any_of!(eager { A, B, C }.map(square) == 25),
+        all_of!(eager { A, B, C }.satisfy(is_even)),
+        none_of!(eager { A, B, C } < 0),
+    ];
+    assert_eq!(EAGER, [true, false, true]);
+
+    const MEMBERSHIP: [bool; 3] = [
+        any_of!(B == {1, 3, 5}),
+        all_of!(C != {1, 3, 5}),
+        none_of!('x' == {'a', 'b'}),
+    ];
+    assert_eq!(MEMBERSHIP, [true, true, true]);
+    const METHODS: SortedStrs<2> = SortedStrs::new(["PUT", "POST"]);
+    const CONTAINED: bool = METHODS.contains("GET");
+    assert!(!CONTAINED);
+
+    assert!(Flag::<{ any_of!({A, B, C} > LIMIT) }>::get());
+    assert!(!Flag::<{ all_of!({A, B, C}.map(double) > 2) }>::get());
+}
@@ -0,0 +1,85 @@
+use crate::constant_time::subtle::{Choice, ConstantTimeEq};
+use crate::ct_all_of;
+use crate::ct_any_of;
+use crate::ct_none_of;
+use crate::{all_of, any_of, none_of};
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+// helper to make the assertions more readable
+fn holds(choice: Choice) -> bool {
+    bool::from(choice)
+}
+
+#[test]
+fn constant_time_comparisons_give_correct_results() {
+    let tag = [1_u8, 2, 3, 4];
+    let keys = [[0_u8, 2, 3, 4], [1_u8, 2, 3, 4], [1_u8, 2, 3, 5]];
+    assert!(holds(ct_any_of!({keys[0],keys[1],keys[2]} == tag)));
+    assert!(!holds(ct_any_of!({keys[0],keys[2]} == tag)));
+    assert!(holds(ct_all_of!({keys[0],keys[2]} != tag)));
+    assert!(!holds(ct_all_of!({keys[0],keys[1]} != tag)));
+    assert!(holds(ct_none_of!({keys[0],keys[2]} == tag)));
+    assert!(!holds(ct_none_of!({keys[0],keys[1],keys[2]} == tag)));
+
+    // slices work as well
+    let slice: &[u8] = &tag;
+    assert!(holds(ct_any_of!({&keys[0][..],&keys[1][..]} == slice)));
+
+    // ordering comparisons on unsigned integers
+    let (a, b, c) = (3_u32, 5_u32, 7_u32);
+    assert!(holds(ct_any_of!({a,b,c} > 6)));
+    assert!(!holds(ct_any_of!({a,b,c} > 7)));
+    assert!(holds(ct_all_of!({a,b,c} >= 3)));
+    assert!(!holds(ct_all_of!({a,b,c} < 7)));
+    assert!(holds(ct_all_of!({a,b,c} <= 7)));
+    assert!(holds(ct_none_of!({a,b,c} < 3)));
+
+    // with map and satisfy
+    assert!(holds(ct_any_of!({a,b,c}.map(|x|x*2) == 10)));
+    assert!(holds(ct_all_of!({a,b,c}.map(|x|x%2) == 1)));
+    assert!(holds(
+        ct_any_of!({keys[0],keys[1]}.satisfy(|key:[u8;4]|key.ct_eq(&tag)))
+    ));
+    assert!(holds(
+        ct_none_of!({keys[0],keys[2]}.satisfy(|key:[u8;4]|key.ct_eq(&tag)))
+    ));
+}
+
+#[test]
+fn constant_time_comparisons_evaluate_all_values() {
+    let count = Cell::new(0);
+    let counted = |x: u64| {
+        count.set(count.get() + 1);
+        x
+    };
+    // the first value decides the result, but all values are compared
+    assert!(holds(ct_any_of!({counted(1),counted(2),counted(3)} == 1)));
+    assert_eq!(count.get(), 3);
+    assert!(!holds(ct_all_of!({1_u64,2,3}.map(counted) == 2)));
+    assert_eq!(count.get(), 6);
+}
+
+#[test]
+// compare the results against the short circuiting macros
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: u8 = rng.gen_range(0..10);
+        let b: u8 = rng.gen_range(0..10);
+        let c: u8 = rng.gen_range(0..10);
+        let rhs: u8 = rng.gen_range(0..10);
+
+        assert_eq!(holds(ct_any_of!({a,b,c} == rhs)), any_of!({a,b,c} == rhs));
+        assert_eq!(holds(ct_any_of!({a,b,c} != rhs)), any_of!({a,b,c} != rhs));
+        assert_eq!(holds(ct_all_of!({a,b,c} < rhs)), all_of!({a,b,c} < rhs));
+        assert_eq!(holds(ct_all_of!({a,b,c} <= rhs)), all_of!({a,b,c} <= rhs));
+        assert_eq!(holds(ct_none_of!({a,b,c} > rhs)), none_of!({a,b,c} > rhs));
+        assert_eq!(
+            holds(ct_none_of!({a,b,c}.map(|x|x/2) >= rhs)),
+            none_of!({a,b,c}.map(|x|x/2) >= rhs)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn eager_comparisons_give_correct_results() {
+    assert!(any_of!(eager { 4 } == 4));
+    assert!(any_of!(eager {1,2,3} > 2));
+    assert_eq!(any_of!(eager {1,2,3} > 3), false);
+    assert!(all_of!(eager {1,2,3} <= 3));
+    assert_eq!(all_of!(eager {1,2,3} < 3), false);
+    assert!(none_of!(eager {1,2,3} > 3));
+    assert_eq!(none_of!(eager {1,2,3} >= 3), false);
+
+    // with map and satisfy
+    assert!(any_of!(eager {1,2,3}.map(|x|x*x) == 4));
+    assert!(all_of!(eager {2,4,6}.satisfy(|x|x%2==0)));
+    assert_eq!(none_of!(eager {1,2,3}.map(|x|x+1) != 3), false);
+    assert!(none_of!(eager {1,3,5}.satisfy(|x|x%2==0)));
+}
+
+#[test]
+fn eager_comparisons_evaluate_all_expressions() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    // the result is known after the first comparison, but all expressions are evaluated
+    assert!(any_of!(eager {counted(1),counted(2),counted(3)} == 1));
+    assert_eq!(count.get(), 3);
+    assert_eq!(all_of!(eager {1,2,3}.map(counted) == 2), false);
+    assert_eq!(count.get(), 6);
+    assert_eq!(none_of!(eager {1,2,3}.satisfy(|x|counted(x)==1)), false);
+    assert_eq!(count.get(), 9);
+
+    // whereas the lazy variant stops after the first comparison
+    assert!(any_of!({counted(1),counted(2),counted(3)} == 1));
+    assert_eq!(count.get(), 10);
+}
+
+#[test]
+// compare the results against the lazy macros
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let d = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+
+        assert_all_eq!(
+            any_of!(eager { a, b, c, d } < rhs),
+            any_of!({a,b,c,d} < rhs)
+        );
+        assert_all_eq!(
+            all_of!(eager { a, b, c, d }.map(|x| x * 2) >= rhs),
+            all_of!({a,b,c,d}.map(|x|x*2) >= rhs)
+        );
+        assert_all_eq!(
+            none_of!(eager { a, b, c, d }.satisfy(|x| x == rhs)),
+            none_of!({a,b,c,d}.satisfy(|x|x==rhs))
+        );
+        assert_all_eq!(
+            none_of!(eager { a, b, c, d } != rhs),
+            none_of!({a,b,c,d} != rhs)
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn enumerate_gives_correct_results() {
+    let (a, b, c) = (5, 15, 22);
+    assert!(all_of!({a,b,c}.enumerate().satisfy(|(i, x)| x < (i + 1) * 10)));
+    assert_eq!(
+        all_of!({a,b,c}.enumerate().satisfy(|(i, x)| x < (i + 1) * 7)),
+        false
+    );
+    assert!(any_of!({a,b,c,}.enumerate().map(|(i, x)| x - i) == 14));
+    assert!(none_of!({a,b,c}.enumerate().map(|(i, x)| x + i) > 30));
+    assert!(any_of!({a,b,c}.enumerate() == (1, 15)));
+    assert!(all_of!({"x","y"}.enumerate().filter(|(i, _)| *i > 0).map(|(_, s)| s) == "y"));
+    assert!(any_of!({ a }.enumerate().satisfy(|(i, _)| i == 0)));
+}
+
+#[test]
+fn zip_gives_correct_results() {
+    let (a, b, c) = (5, 15, 22);
+    assert!(all_of!({a,b,c}.zip({6,16,23}).satisfy(|(x, y)| x < y)));
+    assert_eq!(
+        all_of!({a,b,c}.zip({6,14,23}).satisfy(|(x, y)| x < y)),
+        false
+    );
+    assert!(none_of!({a,b,c}.zip({6,16,23,}).map(|(x, y)| y - x) != 1));
+    assert!(any_of!({a,b,c}.zip({"five","fifteen","twenty two"}) == (15, "fifteen")));
+    assert!(all_of!({a,b,c}.enumerate().zip({1,2,3}).map(|((i, _), y)| y - i) == 1));
+    assert!(all_of!({a,b}.zip({1,2}).enumerate().satisfy(|(i, (_, y))| i + 1 == y)));
+}
+
+#[test]
+fn enumerate_and_zip_are_lazy() {
+    let count = Cell::new(0);
+    let counted = |x: usize| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert!(any_of!({counted(0),counted(1),counted(2)}.enumerate().satisfy(|(i, x)| i == x)));
+    assert_eq!(count.get(), 1);
+    assert_eq!(
+        all_of!({1,2,3}.zip({counted(1),counted(5),counted(3)}).satisfy(|(x, y)| x == y)),
+        false
+    );
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+// compare the results against the equivalent iterator chains
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: usize = rng.gen_range(0..10);
+        let b: usize = rng.gen_range(0..10);
+        let c: usize = rng.gen_range(0..10);
+        let (x, y, z): (i32, i32, i32) = (
+            rng.gen_range(-5..5),
+            rng.gen_range(-5..5),
+            rng.gen_range(-5..5),
+        );
+        let values = [a, b, c];
+        let others = [x, y, z];
+
+        assert_all_eq!(
+            any_of!({a,b,c}.enumerate().satisfy(|(i, v)| v < 3 * i)),
+            values.iter().enumerate().any(|(i, v)| *v < 3 * i)
+        );
+        assert_all_eq!(
+            all_of!({a,b,c}.enumerate().map(|(i, v)| v + i) > 4),
+            values.iter().enumerate().all(|(i, v)| v + i > 4)
+        );
+        assert_all_eq!(
+            none_of!({a,b,c}.zip({x,y,z}).satisfy(|(v, o)| v as i32 == o)),
+            !values
+                .iter()
+                .zip(others.iter())
+                .any(|(v, o)| *v as i32 == *o)
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::argmax_of;
+use crate::argmin_of;
+use crate::max_of;
+use crate::min_of;
+use crate::minmax_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn extrema_give_correct_results() {
+    assert_eq!(min_of!({ 1 }), 1);
+    assert_eq!(min_of!({3, 1, 2,}), 1);
+    assert_eq!(max_of!({3, 1, 2}), 3);
+    assert_eq!(minmax_of!({3, 1, 2}), (1, 3));
+    assert_eq!(minmax_of!({ 1 }), (1, 1));
+    assert_eq!(argmin_of!({3, 1, 2}), 1);
+    assert_eq!(argmax_of!({3, 1, 2}), 0);
+
+    let (a, b, c) = (
+        String::from("pear"),
+        String::from("fig"),
+        String::from("apple"),
+    );
+    assert_eq!(min_of!({a.clone(), b.clone(), c.clone()}), "apple");
+    assert_eq!(
+        max_of!({a.clone(), b.clone(), c.clone()}.map(|s: String| s.len())),
+        5
+    );
+    assert_eq!(min_of!({&a, &b, &c}.by_key(|s| s.len())), "fig");
+    assert_eq!(minmax_of!({&a, &b, &c}.by_key(|s| s.len())), (&b, &c));
+    assert_eq!(argmax_of!({a, b, c}.by_key(String::len)), 2);
+}
+
+#[test]
+fn extrema_of_equal_values_follow_the_standard_library() {
+    // the first minimum and the last maximum, like Iterator::min_by_key and Iterator::max_by_key
+    let (a, b, c) = ("one", "two", "three");
+    assert_eq!(min_of!({a, b, c}.by_key(|s| s.len())), "one");
+    assert_eq!(max_of!({c, a, b}.by_key(|s| s.len())), "three");
+    assert_eq!(max_of!({a, b}.by_key(|s| s.len())), "two");
+    assert_eq!(minmax_of!({a, b}.by_key(|s| s.len())), ("one", "two"));
+    assert_eq!(argmin_of!({1, 0, 0}), 1);
+    assert_eq!(argmax_of!({1, 1, 0}), 1);
+}
+
+#[test]
+fn extrema_skip_nan_values() {
+    let nan = f64::NAN;
+    assert_eq!(min_of!({nan, 2., 1.}), 1.);
+    assert_eq!(min_of!({2., nan, 1.}), 1.);
+    assert_eq!(min_of!({2., 1., nan}), 1.);
+    assert_eq!(max_of!({nan, 2., 1.}), 2.);
+    assert_eq!(max_of!({2., 1., nan}), 2.);
+    assert_eq!(minmax_of!({nan, 2., nan, 1.}), (1., 2.));
+    assert_eq!(argmin_of!({nan, 2., 1.}), 2);
+    assert_eq!(argmax_of!({nan, 2., 1.}), 1);
+    assert_eq!(minmax_of!({nan, 2.}), (2., 2.));
+    assert!(min_of!({nan, nan}).is_nan());
+    assert!(max_of!({ nan }).is_nan());
+    assert_eq!(argmin_of!({nan, nan}), 1);
+    assert_eq!(min_of!({nan, 2., 1.}.map(|x: f64| -x)), -2.);
+    assert_eq!(max_of!({-2., nan, 1.}.by_key(|x: &f64| x.abs())), -2.);
+}
+
+#[test]
+fn extrema_keep_the_first_of_mutually_unordered_values() {
+    #[derive(Debug, PartialEq)]
+    struct Interval(i32, i32);
+    impl PartialOrd for Interval {
+        // intervals are ordered if they do not overlap
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            if self == other {
+                Some(std::cmp::Ordering::Equal)
+            } else if self.1 < other.0 {
+                Some(std::cmp::Ordering::Less)
+            } else if other.1 < self.0 {
+                Some(std::cmp::Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+    assert_eq!(min_of!({Interval(0, 5), Interval(3, 4)}), Interval(0, 5));
+    assert_eq!(max_of!({Interval(0, 5), Interval(3, 4)}), Interval(0, 5));
+    assert_eq!(
+        min_of!({Interval(6, 7), Interval(0, 5), Interval(3, 4)}),
+        Interval(0, 5)
+    );
+}
+
+#[test]
+fn extrema_evaluate_each_value_and_key_once() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert_eq!(min_of!({counted(2), counted(1), counted(3)}), 1);
+    assert_eq!(count.get(), 3);
+    assert_eq!(argmax_of!({counted(2), counted(1), counted(3)}), 2);
+    assert_eq!(count.get(), 6);
+
+    let keys = Cell::new(0);
+    let key = |x: &i32| {
+        keys.set(keys.get() + 1);
+        -x
+    };
+    assert_eq!(min_of!({1, 2, 3}.by_key(key)), 3);
+    assert_eq!(keys.get(), 3);
+    assert_eq!(argmax_of!({1, 2, 3}.by_key(key)), 0);
+    assert_eq!(keys.get(), 6);
+}
+
+#[test]
+// compare the results against the iterator methods of the standard library
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..10000 {
+        let a: i32 = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let d = rng.gen_range(-5..5);
+        let values = [a, b, c, d];
+        let position = |value: &i32| values.iter().position(|v| std::ptr::eq(v, value)).unwrap();
+
+        assert_all_eq!(min_of!({a, b, c, d}), *values.iter().min().unwrap());
+        assert_all_eq!(max_of!({a, b, c, d}), *values.iter().max().unwrap());
+        assert_all_eq!(
+            minmax_of!({a, b, c, d}.map(|x: i32| x * x)),
+            (
+                values.iter().map(|x| x * x).min().unwrap(),
+                values.iter().map(|x| x * x).max().unwrap()
+            )
+        );
+        assert_all_eq!(
+            argmin_of!({a, b, c, d}.by_key(|x| x.abs())),
+            position(values.iter().min_by_key(|x| x.abs()).unwrap())
+        );
+        assert_all_eq!(
+            argmax_of!({a, b, c, d}.by_key(|x| x.abs())),
+            position(values.iter().max_by_key(|x| x.abs()).unwrap())
+        );
+        assert_all_eq!(
+            min_of!({a as f64, b as f64, c as f64, d as f64}),
+            a.min(b).min(c).min(d) as f64
+        );
+    }
+}
@@ -34,3 +34,70 @@ fn test_assert_all_eq_failure_case1() {
 fn test_assert_all_eq_failure_case2() {
     assert_all_eq!(5usize.pow(2), 25, 6 * 6 - 10, 20 + 5);
 }
+
+/// A minimal executor that drives a future to completion on the current thread by
+/// busy polling it. This is enough to test the async macros without depending on a runtime.
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// A future that yields `Pending` a given number of times before it resolves to its value.
+/// It counts how often it was polled, so that we can make sure which futures were polled.
+pub struct CountedFuture<'a, T> {
+    pub value: Option<T>,
+    pub pending_polls: usize,
+    pub poll_count: &'a std::cell::Cell<usize>,
+}
+
+impl<'a, T> CountedFuture<'a, T> {
+    pub fn new(value: T, pending_polls: usize, poll_count: &'a std::cell::Cell<usize>) -> Self {
+        Self {
+            value: Some(value),
+            pending_polls,
+            poll_count,
+        }
+    }
+}
+
+impl<T: Unpin> std::future::Future for CountedFuture<'_, T> {
+    type Output = T;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<T> {
+        self.poll_count.set(self.poll_count.get() + 1);
+        if self.pending_polls > 0 {
+            self.pending_polls -= 1;
+            std::task::Poll::Pending
+        } else {
+            std::task::Poll::Ready(self.value.take().expect("future polled after completion"))
+        }
+    }
+}
+
+#[test]
+fn test_block_on_drives_futures_to_completion() {
+    let poll_count = std::cell::Cell::new(0);
+    assert_eq!(block_on(CountedFuture::new(5, 3, &poll_count)), 5);
+    assert_eq!(poll_count.get(), 4);
+    assert_eq!(block_on(async { 1 + 1 }), 2);
+}
@@ -0,0 +1,147 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+// helper function to produce a random value of three-valued logic
+fn random_option_bool(rng: &mut ThreadRng) -> Option<bool> {
+    match rng.gen_range(0..3) {
+        0 => None,
+        1 => Some(false),
+        _ => Some(true),
+    }
+}
+
+// reference implementation of the Kleene disjunction of a set of values
+fn kleene_or(values: &[Option<bool>]) -> Option<bool> {
+    if values.contains(&Some(true)) {
+        Some(true)
+    } else if values.contains(&None) {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+// reference implementation of the Kleene conjunction of a set of values
+fn kleene_and(values: &[Option<bool>]) -> Option<bool> {
+    if values.contains(&Some(false)) {
+        Some(false)
+    } else if values.contains(&None) {
+        None
+    } else {
+        Some(true)
+    }
+}
+
+#[test]
+fn kleene_quantifiers_give_correct_result_for_option_values() {
+    let t = Some(true);
+    let f = Some(false);
+    let u: Option<bool> = None;
+
+    assert_eq!(any_of!({ t }.kleene()), t);
+    assert_eq!(any_of!({ u }.kleene()), u);
+    assert_eq!(any_of!({f,u,t}.kleene()), t);
+    assert_eq!(any_of!({f,u,f}.kleene()), u);
+    assert_eq!(any_of!({f,f}.kleene()), f);
+
+    assert_eq!(all_of!({ f }.kleene()), f);
+    assert_eq!(all_of!({t,u,f}.kleene()), f);
+    assert_eq!(all_of!({t,u,t}.kleene()), u);
+    assert_eq!(all_of!({t,t}.kleene()), t);
+
+    assert_eq!(none_of!({ t }.kleene()), f);
+    assert_eq!(none_of!({f,u,t}.kleene()), f);
+    assert_eq!(none_of!({f,u,f}.kleene()), u);
+    assert_eq!(none_of!({f,f}.kleene()), t);
+}
+
+#[test]
+fn kleene_quantifiers_give_correct_result_for_predicates_and_comparisons() {
+    let is_even = |x: i32| if x < 0 { None } else { Some(x % 2 == 0) };
+    assert_eq!(any_of!({-1,3,4}.satisfy_kleene(is_even)), Some(true));
+    assert_eq!(any_of!({-1,3,5}.satisfy_kleene(is_even)), None);
+    assert_eq!(all_of!({-1,3,4}.satisfy_kleene(is_even)), Some(false));
+    assert_eq!(all_of!({-1,2,4}.satisfy_kleene(is_even)), None);
+    assert_eq!(none_of!({1,3,5}.satisfy_kleene(is_even)), Some(true));
+    assert_eq!(none_of!({1,-3,5}.satisfy_kleene(is_even)), None);
+
+    let nan = f64::NAN;
+    assert_eq!(any_of!({nan,2.}.kleene()>1.), Some(true));
+    assert_eq!(any_of!({nan,0.}.kleene()>1.), None);
+    assert_eq!(any_of!({0.,0.5}.kleene()>1.), Some(false));
+    assert_eq!(all_of!({nan,0.}.kleene()>1.), Some(false));
+    assert_eq!(all_of!({nan,2.}.kleene()>1.), None);
+    assert_eq!(none_of!({nan,2.}.kleene()>1.), Some(false));
+    assert_eq!(none_of!({0.,0.5}.kleene()!=nan), None);
+    assert_eq!(all_of!({1.,4.}.map(f64::sqrt).kleene()<=2.), Some(true));
+    assert_eq!(any_of!({-1.,4.}.map(f64::sqrt).kleene()<2.), None);
+    assert_eq!(none_of!({-1.,9.}.map(f64::sqrt).kleene()<2.), None);
+}
+
+#[test]
+fn kleene_quantifiers_evaluate_lazily() {
+    let count = Cell::new(0);
+    let counted = |value: Option<bool>| {
+        count.set(count.get() + 1);
+        value
+    };
+    // unknown values do not stop the evaluation
+    assert_eq!(
+        any_of!({counted(None),counted(Some(true)),counted(None)}.kleene()),
+        Some(true)
+    );
+    assert_eq!(count.get(), 2);
+    assert_eq!(
+        all_of!({counted(None),counted(Some(false)),counted(None)}.kleene()),
+        Some(false)
+    );
+    assert_eq!(count.get(), 4);
+    assert_eq!(
+        none_of!({None,Some(true),None}.satisfy_kleene(counted)),
+        Some(false)
+    );
+    assert_eq!(count.get(), 6);
+    assert_eq!(
+        all_of!({None,Some(true),None}.satisfy_kleene(counted)),
+        None
+    );
+    assert_eq!(count.get(), 9);
+}
+
+#[test]
+// compare the results against a reference implementation of the Kleene logic
+fn test_random_collection_of_option_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = random_option_bool(&mut rng);
+        let b = random_option_bool(&mut rng);
+        let c = random_option_bool(&mut rng);
+
+        assert_all_eq!(
+            any_of!({a,b,c}.kleene()),
+            any_of!({a,b,c}.satisfy_kleene(|x|x)),
+            kleene_or(&[a, b, c])
+        );
+        assert_all_eq!(
+            all_of!({a,b,c}.kleene()),
+            all_of!({a,b,c}.satisfy_kleene(|x|x)),
+            kleene_and(&[a, b, c])
+        );
+        assert_all_eq!(
+            none_of!({a,b,c}.kleene()),
+            none_of!({a,b,c}.satisfy_kleene(|x|x)),
+            kleene_or(&[a, b, c]).map(|v| !v)
+        );
+        // the comparisons with the strict modifier agree whenever the strict result is known
+        let x = a.map(|v| v as i32 as f64).unwrap_or(f64::NAN);
+        let y = b.map(|v| v as i32 as f64).unwrap_or(f64::NAN);
+        if let Some(strict) = all_of!({x,y}.strict()<1.) {
+            assert_eq!(all_of!({x,y}.kleene()<1.), Some(strict));
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+// helper function to produce a random number or None with a probability of about 1/4
+fn random_option(rng: &mut ThreadRng) -> Option<i32> {
+    if rng.gen_range(0..4) == 0 {
+        None
+    } else {
+        Some(rng.gen_range(-5..5))
+    }
+}
+
+#[test]
+fn short_circuiting_lifted_comparisons_give_correct_result_for_options() {
+    let (a, b, c) = (Some(1), Some(5), None::<i32>);
+    assert_eq!(any_of!({ a }? == 1), Some(true));
+    assert_eq!(any_of!({a,b}? > 3), Some(true));
+    assert_eq!(any_of!({a,b}? > 5), Some(false));
+    assert_eq!(any_of!({a,c,b}? > 3), None);
+    // the evaluation stops before the None is encountered
+    assert_eq!(any_of!({b,c}? > 3), Some(true));
+
+    assert_eq!(all_of!({a,b}? > 0), Some(true));
+    assert_eq!(all_of!({a,b}? > 3), Some(false));
+    assert_eq!(all_of!({b,c}? > 3), None);
+    assert_eq!(all_of!({a,c}? > 3), Some(false));
+
+    assert_eq!(none_of!({a,b}? > 5), Some(true));
+    assert_eq!(none_of!({a,b}? > 3), Some(false));
+    assert_eq!(none_of!({a,c}? > 3), None);
+    assert_eq!(none_of!({b,c}? > 3), Some(false));
+
+    // with map and satisfy
+    assert_eq!(any_of!({a,b}?.map(|x|x*x) == 25), Some(true));
+    assert_eq!(all_of!({a,c}?.map(|x|x*x) == 1), None);
+    assert_eq!(none_of!({a,b}?.satisfy(|x|x%2==0)), Some(true));
+    assert_eq!(any_of!({c,a}?.satisfy(|x|x%2==1)), None);
+}
+
+#[test]
+fn short_circuiting_lifted_comparisons_give_correct_result_for_results() {
+    let parse = |s: &str| s.parse::<i32>();
+    assert_eq!(any_of!({parse("1"),parse("5")}? > 3), Ok(true));
+    assert_eq!(all_of!({parse("1"),parse("5")}? > 3), Ok(false));
+    assert_eq!(none_of!({parse("1"),parse("5")}? > 5), Ok(true));
+    assert!(all_of!({parse("1"),parse("five")}? > 0).is_err());
+    assert!(none_of!({parse("x"),parse("5")}?.map(|x|x+1) > 0).is_err());
+    assert_eq!(any_of!({parse("1"),parse("five")}? == 1), Ok(true));
+}
+
+#[test]
+fn lifted_comparisons_with_default_give_correct_result() {
+    let (a, b, c) = (Some(1), Some(5), None::<i32>);
+    assert!(any_of!({a,c,b}.lift_or(false) > 3));
+    assert_eq!(any_of!({a,c}.lift_or(false) > 3), false);
+    assert!(any_of!({a,c}.lift_or(true) > 3));
+
+    assert!(all_of!({a,b}.lift_or(false) > 0));
+    assert_eq!(all_of!({a,b,c}.lift_or(false) > 0), false);
+    assert!(all_of!({a,b,c}.lift_or(true) > 0));
+
+    assert!(none_of!({a,c}.lift_or(false) > 3));
+    assert_eq!(none_of!({a,c}.lift_or(true) > 3), false);
+
+    // with map and satisfy
+    assert!(all_of!({a,b,c}.lift_or(true).map(|x|x%2) == 1));
+    assert_eq!(any_of!({ c }.lift_or(false).satisfy(|x| x == 0)), false);
+    assert!(any_of!({"3".parse::<u8>(),"-3".parse::<u8>()}.lift_or(false) == 3));
+}
+
+#[test]
+fn lifted_comparisons_evaluate_lazily() {
+    let count = Cell::new(0);
+    let counted = |value: Option<i32>| {
+        count.set(count.get() + 1);
+        value
+    };
+    assert_eq!(any_of!({counted(Some(5)),counted(None)}? > 3), Some(true));
+    assert_eq!(count.get(), 1);
+    assert_eq!(all_of!({counted(None),counted(Some(1))}? > 3), None);
+    assert_eq!(count.get(), 2);
+    assert!(any_of!({counted(None),counted(Some(5)),counted(None)}.lift_or(false) > 3));
+    assert_eq!(count.get(), 4);
+}
+
+#[test]
+// compare the results against a solution using standard library iterators
+fn test_random_collection_of_options_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = random_option(&mut rng);
+        let b = random_option(&mut rng);
+        let c = random_option(&mut rng);
+        let rhs = rng.gen_range(-5..5);
+
+        // the lifted comparisons agree with the iterator of the contents
+        let values: Option<Vec<i32>> = [a, b, c].iter().copied().collect();
+        if let Some(values) = values {
+            assert_all_eq!(
+                any_of!({a,b,c}? < rhs),
+                Some(values.iter().any(|v| *v < rhs))
+            );
+            assert_all_eq!(
+                all_of!({a,b,c}? < rhs),
+                Some(values.iter().all(|v| *v < rhs))
+            );
+            assert_all_eq!(
+                none_of!({a,b,c}? < rhs),
+                Some(!values.iter().any(|v| *v < rhs))
+            );
+        }
+
+        assert_all_eq!(
+            any_of!({a,b,c}.lift_or(false) >= rhs),
+            [a, b, c].iter().any(|v| v.is_some_and(|v| v >= rhs))
+        );
+        assert_all_eq!(
+            all_of!({a,b,c}.lift_or(true) >= rhs),
+            [a, b, c].iter().all(|v| v.is_none_or(|v| v >= rhs))
+        );
+        assert_all_eq!(
+            none_of!({a,b,c}.lift_or(false).map(|x|x*2) != rhs),
+            ![a, b, c].iter().any(|v| v.is_some_and(|v| v * 2 != rhs))
+        );
+    }
+}
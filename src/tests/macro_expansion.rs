@@ -47,3 +47,40 @@ fn none_of_macro_with_map_expansion_is_same_as_approved_expansion() {
     // and replace macrotest::expand_without_refresh with macrotest::expand
     macrotest::expand("macro_expansion_tests/none_of_with_map_or_satisfies_expansion.rs");
 }
+
+#[test]
+/// test that the macro expansion is the same as a manually approved expansion test file
+fn try_any_of_macro_expansion_is_same_as_approved_expansion() {
+    // to update: delete the .expansion.rs file
+    // and replace macrotest::expand_without_refresh with macrotest::expand
+    macrotest::expand("macro_expansion_tests/try_any_of_expansion.rs");
+}
+
+#[test]
+/// test that the macro expansion is the same as a manually approved expansion test file
+fn any_of_eager_macro_expansion_is_same_as_approved_expansion() {
+    // to update: delete the .expansion.rs file
+    // and replace macrotest::expand_without_refresh with macrotest::expand
+    macrotest::expand("macro_expansion_tests/any_of_eager_expansion.rs");
+}
+
+#[test]
+/// test that the macro expansion is the same as a manually approved expansion test file
+fn any_of_membership_macro_expansion_is_same_as_approved_expansion() {
+    // to update: delete the .expansion.rs file
+    // and replace macrotest::expand_without_refresh with macrotest::expand
+    macrotest::expand("macro_expansion_tests/any_of_membership_expansion.rs");
+}
+
+#[test]
+#[cfg(feature = "constant-time")]
+/// test that the macro expansion is the same as a manually approved expansion test file,
+/// which also makes sure that no short circuiting operators are generated
+fn ct_any_of_macro_expansion_is_same_as_approved_expansion() {
+    // to update: delete the .expansion.rs file
+    // and replace macrotest::expand_without_refresh with macrotest::expand
+    macrotest::expand("macro_expansion_tests/ct_any_of_expansion.rs");
+    let expanded =
+        std::fs::read_to_string("macro_expansion_tests/ct_any_of_expansion.expanded.rs").unwrap();
+    assert!(!expanded.contains("||") && !expanded.contains("&&"));
+}
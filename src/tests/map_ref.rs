@@ -0,0 +1,101 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Measurement {
+    label: String,
+    value: i32,
+}
+
+impl Measurement {
+    fn new(label: &str, value: i32) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+        }
+    }
+}
+
+fn label(measurement: &Measurement) -> &str {
+    &measurement.label
+}
+
+#[test]
+fn borrowing_transformations_give_correct_results_and_do_not_move_values() {
+    let first = Measurement::new("first", 1);
+    let second = Measurement::new("second", 5);
+
+    assert!(any_of!({first, second}.map_ref(|m| m.value) > 4));
+    assert_eq!(any_of!({first, second}.map_ref(|m| m.value) > 5), false);
+    assert!(all_of!({first, second}.map_ref(|m: &Measurement| m.value) >= 1));
+    assert_eq!(all_of!({first, second,}.map_ref(|m| m.value) > 1), false);
+    assert!(none_of!({first, second}.map_ref(|m| m.value) < 0));
+    assert_eq!(none_of!({first, second}.map_ref(|m| m.value) == 5), false);
+
+    assert!(any_of!({first, second}.satisfy_ref(|m| m.label.starts_with('s'))));
+    assert!(all_of!({first, second}.satisfy_ref(|m| m.value % 2 == 1)));
+    assert!(none_of!({first, second}.satisfy_ref(|m| m.label.is_empty())));
+
+    // functions can return data borrowed from the values
+    assert!(any_of!({first, second}.map_ref(label) == "second"));
+    assert!(all_of!({first, second}.map_ref(label) != "third"));
+
+    // the values are still usable
+    assert_eq!(first, Measurement::new("first", 1));
+    assert_eq!(second, Measurement::new("second", 5));
+}
+
+#[test]
+fn borrowing_transformations_accept_values_with_different_lifetimes() {
+    let long_lived = String::from("long lived");
+    assert!(any_of!({String::from("temporary"), long_lived}.map_ref(|s| s.len()) == 10));
+    assert!(all_of!({long_lived, String::from("temporary")}.map_ref(String::as_str) != ""));
+    assert!(none_of!({Measurement::new("a", 1), Measurement::new("b", 2)}.map_ref(label) == "c"));
+    assert_eq!(long_lived, "long lived");
+}
+
+#[test]
+fn borrowing_transformations_are_lazy() {
+    let count = Cell::new(0);
+    let counted = |s: &str| {
+        count.set(count.get() + 1);
+        s.to_string()
+    };
+    assert!(any_of!({counted("a"), counted("bb"), counted("ccc")}.map_ref(|s| s.len()) == 2));
+    assert_eq!(count.get(), 2);
+    assert_eq!(
+        all_of!({counted("a"), counted("bb")}.satisfy_ref(|s| s.len() > 1)),
+        false
+    );
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+// compare the results against the transformations by value
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..10000 {
+        let a = rng.gen_range(-5..5).to_string();
+        let b = rng.gen_range(-5..5).to_string();
+        let c = rng.gen_range(-5..5).to_string();
+        let rhs = rng.gen_range(0..3);
+
+        assert_all_eq!(
+            any_of!({a, b, c}.map_ref(|s| s.len()) > rhs),
+            any_of!({a.clone(), b.clone(), c.clone()}.map(|s: String| s.len()) > rhs)
+        );
+        assert_all_eq!(
+            all_of!({a, b, c}.map_ref(String::len) <= rhs),
+            all_of!({a.clone(), b.clone(), c.clone()}.map(|s: String| s.len()) <= rhs)
+        );
+        assert_all_eq!(
+            none_of!({a, b, c}.satisfy_ref(|s| s.starts_with('-'))),
+            none_of!({a.clone(), b.clone(), c.clone()}.satisfy(|s: String| s.starts_with('-')))
+        );
+    }
+}
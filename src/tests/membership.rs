@@ -0,0 +1,118 @@
+use crate::all_of;
+use crate::any_of;
+use crate::membership::SortedStrs;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+const CODES: [&str; 8] = [
+    "PUT", "GET", "HEAD", "POST", "PATCH", "DELETE", "TRACE", "OPTIONS",
+];
+
+#[test]
+fn membership_tests_give_correct_results() {
+    let x = 5;
+    assert!(any_of!(x == { 5 }));
+    assert!(any_of!(x == {1,5,9,}));
+    assert_eq!(any_of!(x == {1,2,3}), false);
+    assert!(any_of!((x * 2) == {-10, 10}));
+    assert!(any_of!(7 == {1,5,7}));
+    assert!(none_of!(x == {1,2,3}));
+    assert_eq!(none_of!(x == {1,5,9}), false);
+    assert!(all_of!(x != {1,2,3}));
+    assert_eq!(all_of!(x != {1,5,9}), false);
+
+    let letter = 'e';
+    assert!(any_of!(letter == {'a','e','i','o','u'}));
+    assert!(none_of!(letter == {'x','y','z'}));
+    assert!(any_of!((letter.is_alphabetic()) == { true }));
+
+    let method = "HEAD";
+    assert!(any_of!(method == {"GET","HEAD","POST"}));
+    assert!(all_of!(method != {"GET","POST"}));
+}
+
+#[test]
+fn membership_tests_against_string_literals_give_correct_results() {
+    let owned = String::from("PATCH");
+    assert!(any_of!(owned.as_str() == {"PUT","GET","HEAD","POST","PATCH"}));
+    assert!(any_of!("GET" == { "GET" }));
+    assert_eq!(any_of!("" == {"PUT","GET"}), false);
+    assert!(any_of!("" == {"PUT","","GET"}));
+    assert!(none_of!("CONNECT" == {"PUT","GET","HEAD"}));
+    assert_eq!(none_of!("HEAD" == {"PUT","GET","HEAD"}), false);
+    assert!(all_of!("get" != {"PUT","GET","HEAD"}));
+    assert_eq!(all_of!("PUT" != {"PUT","GET","HEAD"}), false);
+    // duplicates and prefixes of other literals
+    assert!(any_of!("GE" == {"GET","GE","G","GET"}));
+    assert_eq!(any_of!("GETS" == {"GET","GE","G","GET"}), false);
+    // escapes and raw strings
+    assert!(any_of!("a\\tb" == {r"a\tb", "\n"}));
+    assert!(any_of!("\n" == {r"a\tb", "\n"}));
+    assert!(none_of!("a\tb" == {r"a\tb", "\n"}));
+}
+
+#[test]
+fn membership_tests_accept_any_expression_as_the_value() {
+    let methods = ["GET", "POST"];
+    assert!(any_of!(methods[1] == {"PUT", "POST"}));
+    assert!(none_of!(methods.len() * 3 == {1, 2, 3, 4, 5}));
+    assert!(all_of!(methods.first().unwrap().len() != {1, 2}));
+    assert!(any_of!(-(methods.len() as i32) == { -2 }));
+    assert!(any_of!(b"abc".as_slice() == {b"abc", b"def"}));
+}
+
+#[test]
+fn sorted_strs_are_sorted_at_compile_time() {
+    const TABLE: SortedStrs<8> = SortedStrs::new(CODES);
+    let mut expected = CODES;
+    expected.sort();
+    assert_eq!(TABLE.as_slice(), expected);
+    assert!(CODES.iter().all(|code| TABLE.contains(code)));
+    assert_eq!(TABLE.contains("CONNECT"), false);
+}
+
+#[test]
+fn membership_tests_evaluate_the_value_once() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert!(any_of!((counted(4)) == {1,2,3,4}));
+    assert_eq!(count.get(), 1);
+    assert!(all_of!((counted(5)) != {1,2,3,4}));
+    assert_eq!(count.get(), 2);
+    assert!(none_of!(counted(5).to_string().as_str() == {"1", "2"}));
+    assert_eq!(count.get(), 3);
+}
+
+#[test]
+// compare the results against the comparison chains
+fn test_random_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let x: i32 = rng.gen_range(-20..20);
+        assert_all_eq!(
+            any_of!(x == {-17, -3, 0, 1, 2, 5, 8, 13, 19}),
+            any_of!({-17, -3, 0, 1, 2, 5, 8, 13, 19} == x)
+        );
+        assert_all_eq!(
+            none_of!(x == {-17, -3, 0, 1, 2, 5, 8, 13, 19}),
+            none_of!({-17, -3, 0, 1, 2, 5, 8, 13, 19} == x)
+        );
+        assert_all_eq!(
+            all_of!(x != {-17, -3, 0, 1, 2, 5, 8, 13, 19}),
+            all_of!({-17, -3, 0, 1, 2, 5, 8, 13, 19} != x)
+        );
+
+        let method = *CODES.choose(&mut rng).unwrap();
+        let truncated = &method[..rng.gen_range(0..=method.len())];
+        assert_all_eq!(
+            any_of!(truncated == {"PUT", "GET", "HEAD", "POST"}),
+            any_of!({"PUT", "GET", "HEAD", "POST"} == truncated)
+        );
+    }
+}
@@ -7,7 +7,41 @@ mod all_of;
 mod all_of_with_map;
 mod any_of;
 mod any_of_with_map;
+mod async_quantifiers;
+mod comparators;
+mod comparison_chains;
+mod concurrent_quantifiers;
+mod const_eval;
+#[cfg(feature = "constant-time")]
+mod constant_time;
+mod eager;
+mod enumerate_and_zip;
+mod extrema;
+mod kleene;
+mod lift;
 mod macro_expansion;
+mod map_ref;
+mod membership;
+// the baseline tests of none_of contain a parenthesized closure body, which newer compilers lint
+#[allow(unused_parens)]
 mod none_of;
 mod none_of_with_map;
+#[cfg(feature = "rayon")]
+mod parallel_quantifiers;
+#[cfg(any(feature = "regex", feature = "glob"))]
+mod patterns;
+mod pipeline;
+mod placeholders;
+#[cfg(feature = "derive")]
+mod quantify_derive;
+#[cfg(feature = "rules")]
+mod rules;
+#[cfg(feature = "serde")]
+mod serialization;
+mod set_relations;
+mod simd;
+mod sortedness;
+mod spread;
 mod theorems;
+mod total_order;
+mod try_quantifiers;
@@ -0,0 +1,105 @@
+use crate::par_all_of;
+use crate::par_any_of;
+use crate::par_none_of;
+use crate::parallel;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rand::prelude::*;
+
+// helper function to double a value
+fn twice(val: i32) -> i32 {
+    2 * val
+}
+
+#[test]
+fn parallel_quantifiers_give_correct_results() {
+    assert!(par_any_of!({ 4 } == 4));
+    assert!(par_any_of!({1,twice(2),3} == 4));
+    assert!(!par_any_of!({1,twice(2),3} == 5));
+    assert!(par_all_of!({1,twice(2),3} <= 4));
+    assert!(!par_all_of!({1,twice(2),3} < 4));
+    assert!(par_none_of!({1,twice(2),3} > 4));
+    assert!(!par_none_of!({1,twice(2),3} >= 4));
+
+    // with map and satisfy
+    assert!(par_any_of!({1,2,3}.map(twice) == 6));
+    assert!(par_all_of!({1,2,3}.satisfy(|x|twice(x)%2==0)));
+    assert!(par_none_of!({1,2,3}.map(|x|x*x) == 5));
+
+    // heterogeneous types on the left hand side
+    let v = [1, 2, 3];
+    assert!(par_all_of!({v.len(),"abc".len(),3} == 3));
+
+    // owned values are moved into the comparisons and the right hand side is not moved
+    let (first, second, limit) = (
+        String::from("one"),
+        String::from("three"),
+        String::from("one"),
+    );
+    assert!(par_any_of!({first, second} > limit));
+    let (first, second) = (vec![1, 2], vec![3]);
+    assert!(par_all_of!({first, second}.map(|v: Vec<i32>| v.len()) < 3));
+    assert_eq!(limit, "one");
+}
+
+#[test]
+fn parallel_functions_give_correct_results() {
+    let values = vec![1, 2, 3, 4];
+    assert!(parallel::any_of(&values, |x| *x == 4));
+    assert!(!parallel::any_of(&values[..2], |x| *x == 4));
+    assert!(parallel::all_of(&values, |x| *x > 0));
+    assert!(!parallel::all_of(0..100, |x| x < 99));
+    assert!(parallel::none_of(values.clone(), |x| x > 4));
+    assert!(!parallel::none_of(values, |x| twice(x) == 8));
+}
+
+#[test]
+fn parallel_quantifiers_stop_once_the_result_is_known() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let count = AtomicUsize::new(0);
+    let counted = |x: i32| {
+        count.fetch_add(1, Ordering::SeqCst);
+        x
+    };
+    pool.install(|| {
+        assert!(par_any_of!({1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16}.map(counted) == 1));
+    });
+    assert!(count.load(Ordering::SeqCst) < 16);
+
+    count.store(0, Ordering::SeqCst);
+    pool.install(|| {
+        assert!(!par_all_of!({0,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16}.map(counted) > 0));
+    });
+    assert!(count.load(Ordering::SeqCst) < 16);
+}
+
+#[test]
+// compare the results against a solution using standard library iterators
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..10000 {
+        let a = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let d = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+
+        assert_all_eq!(
+            par_any_of!({a,b,c,d} < rhs),
+            [a, b, c, d].iter().any(|v| *v < rhs)
+        );
+        assert_all_eq!(
+            par_all_of!({a,b,c,d}.map(|x|x+1) >= rhs),
+            par_all_of!({a,b,c,d}.satisfy(|x|x+1 >= rhs)),
+            [a, b, c, d].iter().all(|v| v + 1 >= rhs)
+        );
+        assert_all_eq!(
+            par_none_of!({a,b,c,d}.map(twice) != rhs),
+            ![a, b, c, d].iter().any(|v| twice(*v) != rhs)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_matches_give_correct_results() {
+    let forwarded = regex::Regex::new("^X-Forwarded-").unwrap();
+    let (first, second) = ("X-Forwarded-For", String::from("Host"));
+    assert!(any_of!({first, &second}.matches_regex(&forwarded)));
+    assert_eq!(all_of!({first, &second}.matches_regex(&forwarded)), false);
+    assert!(all_of!({first, "X-Forwarded-Host",}.matches_regex(&forwarded)));
+    assert!(none_of!({&second, "Accept"}.matches_regex(&forwarded)));
+    assert!(any_of!({first, second.as_str()}.matches_regex(forwarded.clone())));
+
+    // patterns given as strings and transformations
+    assert_eq!(all_of!({"a1", "b22"}.matches_regex(r"^\w\d+$")), Ok(true));
+    assert_eq!(
+        any_of!({first, second.as_str()}.map(|s: &str| s.to_lowercase()).matches_regex("^host$")),
+        Ok(true)
+    );
+    assert_eq!(
+        none_of!({1, 22}.map(|x: i32| x.to_string()).matches_regex("^3")),
+        Ok(true)
+    );
+
+    // spread values and placeholders
+    let headers = vec![first, "Accept"];
+    assert!(any_of!({ ..&headers }.matches_regex(&forwarded)));
+    assert_eq!(all_of!({ ..&headers }.matches_regex("^[A-Z]")), Ok(true));
+    assert_eq!(
+        none_of!({ ..&headers }
+            .map(|s: &&str| s.len().to_string())
+            .matches_regex("^1")),
+        Ok(false)
+    );
+    let all_forwarded = all_of!({_: [&str; 2]}.matches_regex(&forwarded));
+    assert!(all_forwarded([first, "X-Forwarded-Host"]));
+    assert!(!all_forwarded([first, "Host"]));
+
+    // patterns given as owned strings, e.g. from a configuration
+    let pattern = String::from("^X-");
+    assert_eq!(
+        all_of!({first, "X-Forwarded-Host"}.matches_regex(&pattern)),
+        Ok(true)
+    );
+    assert_eq!(any_of!({ ..&headers }.matches_regex(pattern)), Ok(true));
+    assert!(none_of!({ &second }.matches_regex(String::from("(unclosed"))).is_err());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn invalid_regex_strings_give_errors() {
+    let count = Cell::new(0);
+    let counted = |s: &'static str| {
+        count.set(count.get() + 1);
+        s
+    };
+    assert!(any_of!({ counted("a") }.matches_regex("(unclosed")).is_err());
+    assert!(all_of!({ ..["a", "b"] }.matches_regex("(unclosed")).is_err());
+    assert!(none_of!({ _ }.matches_regex("(unclosed"))(("a", "b")).is_err());
+    // the values are not evaluated if the pattern is invalid
+    assert_eq!(count.get(), 0);
+}
+
+#[cfg(feature = "glob")]
+#[test]
+fn glob_matches_give_correct_results() {
+    use std::path::{Path, PathBuf};
+    let sources = glob::Pattern::new("src/**/*.rs").unwrap();
+    let (lib, readme) = (Path::new("src/lib.rs"), PathBuf::from("README.md"));
+    assert!(any_of!({lib, &readme}.matches_glob(&sources)));
+    assert_eq!(all_of!({lib, &readme}.matches_glob(&sources)), false);
+    assert!(all_of!({lib, "src/tests/mod.rs"}.matches_glob(&sources)));
+    assert!(none_of!({&readme, "Cargo.toml"}.matches_glob(sources.clone())));
+
+    // patterns given as strings and transformations
+    assert_eq!(
+        any_of!({"main.rs", "main.c"}.matches_glob("*.[ch]")).ok(),
+        Some(true)
+    );
+    assert_eq!(
+        none_of!({"a.RS", "b.Rs"}.map(|s: &str| s.to_uppercase()).matches_glob("*.rs")).ok(),
+        Some(true)
+    );
+
+    // spread values
+    let paths = [lib, Path::new("src/tests/mod.rs")];
+    assert!(all_of!({ ..paths }.matches_glob(&sources)));
+    assert_eq!(
+        any_of!({ ..paths }.matches_glob("*.toml")).ok(),
+        Some(false)
+    );
+
+    // patterns given as owned strings, e.g. from a configuration
+    let pattern = String::from("src/*.rs");
+    assert_eq!(
+        any_of!({lib, &readme}.matches_glob(&pattern)).ok(),
+        Some(true)
+    );
+    assert_eq!(all_of!({ ..paths }.matches_glob(pattern)).ok(), Some(true));
+    assert!(any_of!({ &readme }.matches_glob(String::from("***"))).is_err());
+}
+
+#[cfg(feature = "glob")]
+#[test]
+fn invalid_glob_strings_give_errors() {
+    assert!(any_of!({ "a" }.matches_glob("***")).is_err());
+    assert!(none_of!({ ..["a", "b"] }.matches_glob("***")).is_err());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern_matches_are_lazy_and_compile_the_pattern_once() {
+    let count = Cell::new(0);
+    let counted = |s: &'static str| {
+        count.set(count.get() + 1);
+        s
+    };
+    let compiled = Cell::new(0);
+    let pattern = || {
+        compiled.set(compiled.get() + 1);
+        "^X-"
+    };
+    assert_eq!(
+        any_of!({counted("X-a"), counted("b"), counted("c")}.matches_regex(pattern())),
+        Ok(true)
+    );
+    assert_eq!((count.get(), compiled.get()), (1, 1));
+    assert_eq!(
+        all_of!({counted("X-a"), counted("b"), counted("X-c")}.matches_regex(pattern())),
+        Ok(false)
+    );
+    assert_eq!((count.get(), compiled.get()), (3, 2));
+
+    // the same goes for spread values and placeholders
+    let values = ["X-a", "X-b", "c"];
+    assert_eq!(
+        all_of!({ ..values.map(counted) }.matches_regex(pattern())),
+        Ok(false)
+    );
+    assert_eq!((count.get(), compiled.get()), (6, 3));
+    assert_eq!(
+        none_of!({ ..values.iter().map(|s| counted(s)) }.matches_regex(pattern())),
+        Ok(false)
+    );
+    assert_eq!((count.get(), compiled.get()), (7, 4));
+    let any_matches = any_of!({_: [&str; 3]}.matches_regex(pattern()));
+    assert_eq!(any_matches(values), Ok(true));
+    assert_eq!(compiled.get(), 5);
+}
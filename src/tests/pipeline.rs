@@ -0,0 +1,140 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+fn parse(s: &str) -> i32 {
+    s.parse().unwrap()
+}
+
+fn is_small(x: i32) -> bool {
+    x.abs() < 10
+}
+
+#[test]
+fn chains_of_transformations_give_correct_results() {
+    let (a, b, c) = ("-3", "12", "4");
+    assert!(any_of!({a,b,c}.map(parse).map(i32::abs).satisfy(|x| x > 10)));
+    assert_eq!(
+        all_of!({a,b,c}.map(parse).map(i32::abs).satisfy(is_small)),
+        false
+    );
+    assert_eq!(
+        none_of!({a,b,c}.map(parse).map(|x| x * x).satisfy(|x| x % 2 == 0)),
+        false
+    );
+    assert!(all_of!({a,b,c}.map(parse).map(|x| x as f64 / 2.) < 6.5));
+
+    assert!(any_of!({a,b,c}.map(parse).filter(|x| *x > 0).map(|x| x * 2) == 8));
+    assert!(all_of!({a,b,c}.map(parse).filter(|x| *x > 0) > 3));
+    assert_eq!(all_of!({a,b,c}.map(parse).filter(|x| *x < 10) > 0), false);
+    assert!(none_of!({a,b,c}.filter(|s| s.len() == 1).map(parse) > 4));
+    assert!(any_of!({a,b,c}.filter(|s| s.starts_with('-')).satisfy(|s| s.len() == 2)));
+    assert!(
+        all_of!({a,b,c}.map(parse).filter(|x| x % 2 == 0).filter(|x| *x > 5).satisfy(|x| x == 12))
+    );
+    assert!(any_of!({ 1, 2, 3 }.filter(|x| *x > 1) < 3));
+}
+
+#[test]
+fn filters_that_skip_all_values_follow_vacuous_truth() {
+    let no_values: [i32; 0] = [];
+    assert_eq!(
+        any_of!({1,2,3}.filter(|_| false) > 0),
+        no_values.iter().any(|x| *x > 0)
+    );
+    assert_eq!(
+        all_of!({1,2,3}.filter(|_| false) < 0),
+        no_values.iter().all(|x| *x < 0)
+    );
+    assert!(none_of!({1,2,3}.filter(|_| false) > 0));
+    assert_eq!(any_of!({1,2,3}.filter(|_| false).satisfy(|_| true)), false);
+    assert!(all_of!({1,2,3}.map(|x| x - 1).filter(|x| *x > 5).satisfy(|_| false)));
+    assert!(none_of!({1,2,3}.filter(|_| false).satisfy(|_| true)));
+}
+
+#[test]
+fn chains_of_transformations_are_lazy() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    // each value goes through the whole chain before the next value is evaluated
+    assert!(any_of!({counted(1),counted(2),counted(3)}.map(|x| x * 2).map(counted) == 4));
+    assert_eq!(count.get(), 4);
+    // skipped values are not passed to the rest of the chain
+    assert!(all_of!({1,2,3,4}.filter(|x| x % 2 == 0).map(counted) > 0));
+    assert_eq!(count.get(), 6);
+    assert_eq!(
+        none_of!({1,2,3}.map(counted).filter(|x| *x > 1) < 10),
+        false
+    );
+    assert_eq!(count.get(), 8);
+}
+
+#[test]
+fn transformations_of_a_chain_are_evaluated_once() {
+    let count = Cell::new(0);
+    let make_step = || {
+        count.set(count.get() + 1);
+        |x: i32| x + 1
+    };
+    let make_filter = || {
+        count.set(count.get() + 1);
+        |x: &i32| *x > 0
+    };
+    assert!(all_of!({1,2,3,4}.map(make_step()).filter(make_filter()).map(make_step()) > 2));
+    assert_eq!(count.get(), 3);
+    let make_predicate = || {
+        count.set(count.get() + 1);
+        |x: i32| x > 5
+    };
+    assert!(!any_of!({1,2,3}.filter(make_filter()).satisfy(make_predicate())));
+    assert_eq!(count.get(), 5);
+    // the steps are evaluated even if there are no values left to transform
+    assert!(none_of!({-1,-2}.filter(make_filter()).map(make_step()) > 0));
+    assert_eq!(count.get(), 7);
+}
+
+#[test]
+// compare the results against the equivalent iterator chains
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+        let values = [a, b, c];
+
+        assert_all_eq!(
+            any_of!({a,b,c}.map(|x| x * 2).filter(|x| *x > rhs).map(|x| x - 1) < 3),
+            values
+                .iter()
+                .map(|x| x * 2)
+                .filter(|x| *x > rhs)
+                .map(|x| x - 1)
+                .any(|x| x < 3)
+        );
+        assert_all_eq!(
+            all_of!({a,b,c}.filter(|x| *x != rhs).map(i32::abs) >= 2),
+            values
+                .iter()
+                .filter(|x| **x != rhs)
+                .map(|x| x.abs())
+                .all(|x| x >= 2)
+        );
+        assert_all_eq!(
+            none_of!({a,b,c}.map(|x| x + rhs).filter(|x| x % 2 == 0).satisfy(|x| x > 0)),
+            !values
+                .iter()
+                .map(|x| x + rhs)
+                .filter(|x| x % 2 == 0)
+                .any(|x| x > 0)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use crate::values::{self, Values};
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn rhs_placeholders_give_correct_closures() {
+    let (a, b, c) = (1, 5, 9);
+    let any_greater = any_of!({a,b,c} > _);
+    assert!(any_greater(8));
+    assert_eq!(any_greater(9), false);
+    let all_greater = all_of!({a,b,c,} > _);
+    assert!(all_greater(0));
+    assert_eq!(all_greater(1), false);
+    let none_equal = none_of!({a,b,c} == _);
+    assert!(none_equal(4));
+    assert_eq!(none_equal(5), false);
+
+    // with a modifier in front of the operator
+    assert!(any_of!({a,b,c}.map(|x| x * 2) == _)(18));
+    // NaN is greater than infinity in the total order
+    assert_eq!(all_of!({1., f64::NAN}.total() < _)(f64::INFINITY), false);
+    assert!(none_of!({"Guest","ADMIN"}.by_key(str::to_lowercase) == *_)(
+        "root"
+    ));
+
+    // the closures can be stored
+    struct Config {
+        is_valid: Box<dyn Fn(i32) -> bool>,
+    }
+    let config = Config {
+        is_valid: Box::new(all_of!({a,b,c} < _)),
+    };
+    assert!((config.is_valid)(10));
+    assert_eq!((config.is_valid)(9), false);
+}
+
+#[test]
+fn dereferencing_rhs_placeholders_work_with_filter() {
+    let (a, b, c) = (1, 5, 9);
+    let values = [0, 3, 7, 10];
+    let filtered: Vec<i32> = values
+        .iter()
+        .copied()
+        .filter(any_of!({a,b,c} > *_))
+        .collect();
+    assert_eq!(filtered, [0, 3, 7]);
+    let filtered: Vec<&i32> = values.iter().filter(none_of!({&a,&b,&c} > *_)).collect();
+    assert_eq!(filtered, [&10]);
+}
+
+#[test]
+fn set_placeholders_give_correct_closures() {
+    let limit = 10;
+    let all_small = all_of!({ _ } < limit);
+    assert!(all_small([1, 2, 3]));
+    assert_eq!(all_small([1, 20, 3]), false);
+    assert!(any_of!({_: (i32, i32)} > limit)((1, 11)));
+    assert!(none_of!({_: &[i32]} == &limit)(&[1, 2, 3][..]));
+    assert_eq!(none_of!({_: &[i32; 2]} == &limit)(&[10, 2]), false);
+
+    // with modifiers and chains
+    assert!(all_of!({_: [i32; 3]}.map(|x| x * x) < 50)([1, 2, 7]));
+    assert!(all_of!({_: [i32; 3]}.filter(|x| *x > 1).map(|x| x * 2) > 3)([0, 2, 7]));
+    assert!(any_of!({_: [i32; 3]}.satisfy(|x| x % 2 == 0))([1, 2, 3]));
+    assert!(none_of!({_: [&str; 2]}.by_key(str::len) > "abc")([
+        "ab", "cd"
+    ]));
+}
+
+#[test]
+fn placeholder_closures_are_lazy_and_evaluate_the_rhs_once() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    let any_greater = any_of!({counted(1), counted(5), counted(9)} > _);
+    assert_eq!(count.get(), 0);
+    assert!(any_greater(3));
+    assert_eq!(count.get(), 2);
+    assert_eq!(any_greater(10), false);
+    assert_eq!(count.get(), 5);
+
+    let rhs_count = Cell::new(0);
+    let rhs = || {
+        rhs_count.set(rhs_count.get() + 1);
+        5
+    };
+    let all_small = all_of!({_: [i32; 4]}.map(counted) < rhs());
+    assert!(all_small([1, 2, 3, 4]));
+    assert_eq!((rhs_count.get(), count.get()), (1, 9));
+    assert_eq!(all_small([1, 7, 3, 4]), false);
+    assert_eq!((rhs_count.get(), count.get()), (2, 11));
+}
+
+#[test]
+fn values_are_given_from_left_to_right() {
+    assert!([1, 2, 3].into_values().eq([1, 2, 3]));
+    assert!((1, 2, 3).into_values().eq([1, 2, 3]));
+    assert!((1,).into_values().eq([1]));
+    assert!((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)
+        .into_values()
+        .eq(1..=12));
+    assert!(Values::into_values(&[1, 2][..]).eq(&[1, 2]));
+    assert!(Values::into_values(&[1, 2]).eq(&[1, 2]));
+}
+
+#[test]
+fn values_functions_give_correct_results() {
+    assert!(values::any_of([1, 5, 9], |x| x > 8));
+    assert!(!values::all_of((1, 5, 9), |x| x > 1));
+    assert!(values::none_of(&[1, 5, 9][..], |x| *x > 9));
+    assert!(!values::none_of([1, 5, 9], |x| x > 8));
+    assert!(values::none_of([0; 0], |x: i32| x > 0));
+}
+
+#[test]
+// compare the results against the macros without placeholders
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: i32 = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+
+        assert_all_eq!(any_of!({a,b,c} < _)(rhs), any_of!({a,b,c} < rhs));
+        assert_all_eq!(all_of!({a,b,c} >= *_)(&rhs), all_of!({a,b,c} >= rhs));
+        assert_all_eq!(
+            none_of!({a,b,c}.map(|x| x * x) == _)(rhs),
+            none_of!({a,b,c}.map(|x| x * x) == rhs)
+        );
+        assert_all_eq!(any_of!({ _ } <= rhs)([a, b, c]), any_of!({a,b,c} <= rhs));
+        assert_all_eq!(all_of!({ _ } != rhs)((a, b, c)), all_of!({a,b,c} != rhs));
+        assert_all_eq!(
+            none_of!({_: [i32; 3]}.filter(|x| *x > 0) > rhs)([a, b, c]),
+            none_of!({a,b,c}.filter(|x| *x > 0) > rhs)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use crate::Quantify;
+
+#[derive(Debug, Quantify)]
+struct Usage {
+    #[quantify(group = "load")]
+    cpu: u32,
+    #[quantify(group = "load", group = "io")]
+    mem: u32,
+    #[quantify(group = "io")]
+    disk: u32,
+    network: u32,
+    #[quantify(skip)]
+    host: String,
+}
+
+impl Usage {
+    fn new(cpu: u32, mem: u32, disk: u32, network: u32) -> Self {
+        Self {
+            cpu,
+            mem,
+            disk,
+            network,
+            host: String::from("db"),
+        }
+    }
+}
+
+#[derive(Quantify)]
+struct Interval<T>(T, T);
+
+#[test]
+fn derived_field_quantifiers_give_correct_results() {
+    let usage = Usage::new(40, 95, 10, 20);
+    assert_eq!(usage.fields(), [&40, &95, &10, &20]);
+    assert!(usage.any_fields(|v| *v > 90));
+    assert_eq!(usage.all_fields(|v| *v < 90), false);
+    assert!(usage.all_fields(|v| *v >= 10));
+    assert!(usage.none_fields(|v| *v == 0));
+    assert_eq!(usage.none_fields(|v| *v == 95), false);
+    assert_eq!(usage.host, "db");
+
+    let interval = Interval(1.5, 2.5);
+    assert_eq!(interval.fields(), [&1.5, &2.5]);
+    assert!(interval.all_fields(|v| *v > 1.));
+}
+
+#[test]
+fn derived_field_groups_give_correct_results() {
+    let usage = Usage::new(40, 95, 10, 20);
+    assert!(usage.fields_in_group("load").eq([&40, &95]));
+    assert!(usage.fields_in_group("io").eq([&95, &10]));
+    assert_eq!(usage.fields_in_group("unknown").count(), 0);
+}
+
+#[test]
+fn derived_fields_can_be_spread_into_the_macros() {
+    let usage = Usage::new(40, 95, 10, 20);
+    assert!(any_of!({ ..&usage } > &90));
+    assert_eq!(all_of!({ ..&usage } < &90), false);
+    assert!(all_of!({ ..usage.fields_in_group("io") } >= &10));
+    assert!(none_of!({ ..usage.fields_in_group("load") } < &40));
+    assert!(all_of!({ ..&Interval(1, 2) }.satisfy(|v: &i32| *v > 0)));
+
+    let mut visited = Vec::new();
+    for value in &usage {
+        visited.push(*value);
+    }
+    assert_eq!(visited, [40, 95, 10, 20]);
+}
+
+#[test]
+fn derived_field_quantifiers_are_lazy() {
+    let usage = Usage::new(40, 95, 10, 20);
+    let mut visited = 0;
+    assert!(usage.any_fields(|v| {
+        visited += 1;
+        *v > 90
+    }));
+    assert_eq!(visited, 2);
+}
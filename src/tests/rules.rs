@@ -0,0 +1,420 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use crate::rules::*;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use rand::prelude::*;
+
+fn double(x: f64) -> f64 {
+    2. * x
+}
+
+fn negate(x: f64) -> f64 {
+    -x
+}
+
+fn registry() -> Registry {
+    Registry::new()
+        .with_map("double", double)
+        .with_map("negate", negate)
+}
+
+fn variable(name: &str) -> Operand {
+    Operand::Variable(name.to_string())
+}
+
+#[test]
+fn rules_are_parsed_into_the_correct_syntax_tree() {
+    let rule = Rule::parse(
+        "any_of!({cpu, 1_000.5,}.map(double) >= -2e1) || !none_of({mem} in ..=limit) && all_of({x} in 1..)",
+        &registry(),
+    )
+    .unwrap();
+    let expected = Rule::Or(
+        Box::new(Rule::Quantified(Quantified {
+            quantifier: Quantifier::AnyOf,
+            set: vec![variable("cpu"), Operand::Number(1000.5)],
+            maps: vec!["double".to_string()],
+            comparison: Comparison::Compare(Operator::Ge, Operand::Number(-20.)),
+        })),
+        Box::new(Rule::And(
+            Box::new(Rule::Not(Box::new(Rule::Quantified(Quantified {
+                quantifier: Quantifier::NoneOf,
+                set: vec![variable("mem")],
+                maps: vec![],
+                comparison: Comparison::InRange(Range {
+                    start: None,
+                    end: Some(variable("limit")),
+                    inclusive: true,
+                }),
+            })))),
+            Box::new(Rule::Quantified(Quantified {
+                quantifier: Quantifier::AllOf,
+                set: vec![variable("x")],
+                maps: vec![],
+                comparison: Comparison::InRange(Range {
+                    start: Some(Operand::Number(1.)),
+                    end: None,
+                    inclusive: false,
+                }),
+            })),
+        )),
+    );
+    assert_eq!(rule, expected);
+
+    // parentheses group rules and whitespace is insignificant
+    let grouped = Rule::parse(
+        "( any_of({a}<1)||any_of({b}<2) )\n\t&& any_of({c}<3)",
+        &registry(),
+    )
+    .unwrap();
+    assert!(matches!(grouped, Rule::And(lhs, _) if matches!(*lhs, Rule::Or(_, _))));
+}
+
+#[test]
+fn parse_errors_report_their_position() {
+    let error = |text: &str| Rule::parse(text, &registry()).unwrap_err();
+    let expected = |kind, line, column| ParseError { kind, line, column };
+
+    assert_eq!(
+        error("any_of({a, b} > 1) &&\n  some_of({c} > 2)"),
+        expected(
+            ParseErrorKind::UnknownQuantifier("some_of".to_string()),
+            2,
+            3
+        )
+    );
+    assert_eq!(
+        error("all_of({a}.map(square) > 1)"),
+        expected(ParseErrorKind::UnknownMap("square".to_string()), 1, 16)
+    );
+    assert_eq!(
+        error("all_of({a}.filter(double) > 1)"),
+        expected(
+            ParseErrorKind::Expected {
+                expected: "`map`",
+                found: "`filter`".to_string()
+            },
+            1,
+            12
+        )
+    );
+    assert_eq!(
+        error("none_of({} > 1)"),
+        expected(
+            ParseErrorKind::Expected {
+                expected: "a variable or a number",
+                found: "`}`".to_string()
+            },
+            1,
+            10
+        )
+    );
+    assert_eq!(
+        error("any_of({a} = 1)"),
+        expected(ParseErrorKind::UnexpectedCharacter('='), 1, 12)
+    );
+    assert_eq!(
+        error("any_of({a} in ..)"),
+        expected(ParseErrorKind::FullRange, 1, 15)
+    );
+    assert_eq!(
+        error("any_of({a} in 1..=)"),
+        expected(
+            ParseErrorKind::Expected {
+                expected: "a variable or a number",
+                found: "`)`".to_string()
+            },
+            1,
+            19
+        )
+    );
+    assert_eq!(
+        error("any_of({a} > -b)"),
+        expected(
+            ParseErrorKind::Expected {
+                expected: "a number",
+                found: "`b`".to_string()
+            },
+            1,
+            15
+        )
+    );
+    assert_eq!(
+        error("any_of({a} > 1"),
+        expected(
+            ParseErrorKind::Expected {
+                expected: "`)`",
+                found: "end of input".to_string()
+            },
+            1,
+            15
+        )
+    );
+    assert_eq!(
+        error("any_of({a} > 1) any_of({b} > 1)"),
+        expected(
+            ParseErrorKind::Expected {
+                expected: "`&&`, `||` or end of input",
+                found: "`any_of`".to_string()
+            },
+            1,
+            17
+        )
+    );
+    assert_eq!(
+        error("any_of({a} > 1)\n&& (all_of({ä} > 1))").to_string(),
+        "unexpected character `ä` at line 2, column 13"
+    );
+}
+
+#[test]
+fn deeply_nested_rules_are_rejected() {
+    let parse = |text: &str| Rule::parse(text, &registry());
+    let too_deep = |column| ParseError {
+        kind: ParseErrorKind::TooDeeplyNested,
+        line: 1,
+        column,
+    };
+    let comparison = "any_of({a} > 1)";
+
+    let negations = "!".repeat(MAX_NESTING) + comparison;
+    assert_eq!(
+        parse(&negations)
+            .unwrap()
+            .evaluate(&|_: &str| Some(2.), &registry()),
+        Ok(true)
+    );
+    assert_eq!(
+        parse(&format!("!{}", negations)),
+        Err(too_deep(MAX_NESTING + 1))
+    );
+
+    // the parser stops at the limit rather than overflowing the stack
+    let parentheses = "(".repeat(100_000) + comparison + &")".repeat(100_000);
+    assert_eq!(parse(&parentheses), Err(too_deep(MAX_NESTING + 1)));
+    assert!(parse(&("(".repeat(MAX_NESTING) + comparison + &")".repeat(MAX_NESTING))).is_ok());
+
+    // each operator of a chain adds a level to the tree of the rule
+    let chain = |count: usize| vec![comparison; count].join(" && ");
+    assert!(parse(&chain(MAX_NESTING + 1)).is_ok());
+    let column = (MAX_NESTING + 1) * (comparison.len() + 4) - 2;
+    assert_eq!(parse(&chain(MAX_NESTING + 2)), Err(too_deep(column)));
+    assert_eq!(
+        parse(&format!("!({})", chain(MAX_NESTING + 1)))
+            .unwrap_err()
+            .to_string(),
+        format!(
+            "the rule is nested deeper than {} levels at line 1, column 1",
+            MAX_NESTING
+        )
+    );
+}
+
+#[test]
+fn rules_evaluate_to_correct_results() {
+    let registry = registry();
+    let mut variables = BTreeMap::new();
+    variables.insert("cpu", 95.);
+    variables.insert("mem", 40.);
+    variables.insert("disk", 3.);
+    variables.insert("nan", f64::NAN);
+    let evaluate = |text: &str| {
+        Rule::parse(text, &registry)
+            .unwrap()
+            .evaluate(&variables, &registry)
+    };
+
+    assert_eq!(evaluate("any_of({cpu, mem} > 90)"), Ok(true));
+    assert_eq!(evaluate("all_of({cpu, mem} > 90)"), Ok(false));
+    assert_eq!(evaluate("none_of({cpu, mem} > 100)"), Ok(true));
+    assert_eq!(
+        evaluate("any_of({cpu, mem} > 90) && none_of({disk} < 5)"),
+        Ok(false)
+    );
+    assert_eq!(
+        evaluate("any_of({cpu, mem} > 90) && !none_of({disk} < 5)"),
+        Ok(true)
+    );
+    assert_eq!(
+        evaluate("all_of({mem}.map(double).map(negate) == -80)"),
+        Ok(true)
+    );
+    assert_eq!(evaluate("all_of({mem, disk} in disk..=mem)"), Ok(true));
+    assert_eq!(evaluate("any_of({mem, disk} in disk..mem)"), Ok(true));
+    assert_eq!(evaluate("all_of({mem, disk} in disk..mem)"), Ok(false));
+    assert_eq!(evaluate("any_of({cpu} in ..95)"), Ok(false));
+    assert_eq!(
+        evaluate("any_of({nan} in ..95) || any_of({nan} >= nan)"),
+        Ok(false)
+    );
+    assert_eq!(evaluate("all_of({nan} != nan)"), Ok(true));
+}
+
+#[test]
+fn rules_only_look_up_the_variables_they_need() {
+    let registry = registry();
+    let evaluate = |text: &str, variables: &dyn Fn(&str) -> Option<f64>| {
+        Rule::parse(text, &registry)
+            .unwrap()
+            .evaluate(variables, &registry)
+    };
+    let only_one = |name: &str| if name == "one" { Some(1.) } else { None };
+
+    assert_eq!(evaluate("any_of({one, missing} == 1)", &only_one), Ok(true));
+    assert_eq!(evaluate("all_of({one, missing} > 1)", &only_one), Ok(false));
+    assert_eq!(
+        evaluate(
+            "none_of({one, missing} in ..=missing) || any_of({missing} > 1)",
+            &only_one
+        ),
+        Err(EvalError::UnknownVariable("missing".to_string()))
+    );
+    assert_eq!(
+        evaluate("all_of({one} < 1) && any_of({missing} > 1)", &only_one),
+        Ok(false)
+    );
+    assert_eq!(
+        evaluate("all_of({one} == 1) && any_of({missing} > 1)", &only_one),
+        Err(EvalError::UnknownVariable("missing".to_string()))
+    );
+
+    // maps are looked up in the registry before the values
+    let rule = Rule::parse("any_of({missing}.map(double) > 1)", &registry).unwrap();
+    assert_eq!(
+        rule.evaluate(&only_one, &Registry::new()),
+        Err(EvalError::UnknownMap("double".to_string()))
+    );
+    assert_eq!(
+        EvalError::UnknownMap("double".to_string()).to_string(),
+        "unknown map `double`"
+    );
+}
+
+/// Assert that the rule gives the same result as the macro invocation and that both look up the same
+/// variables in the same order. The invocation looks up variables with the closure given as `|get|`.
+macro_rules! assert_rule_agrees {
+    ($variables:expr, $rule:literal, |$get:ident| $invocation:expr) => {{
+        let registry = registry();
+        let macro_lookups = RefCell::new(Vec::new());
+        let $get = |name: &'static str| {
+            macro_lookups.borrow_mut().push(name.to_string());
+            $variables[name]
+        };
+        let expected = $invocation;
+
+        let rule_lookups = RefCell::new(Vec::new());
+        let variables = |name: &str| {
+            rule_lookups.borrow_mut().push(name.to_string());
+            $variables.get(name).copied()
+        };
+        let result = Rule::parse($rule, &registry)
+            .unwrap()
+            .evaluate(&variables, &registry);
+        assert_eq!(result, Ok(expected), "{} for {:?}", $rule, $variables);
+        assert_eq!(
+            rule_lookups, macro_lookups,
+            "{} for {:?}",
+            $rule, $variables
+        );
+    }};
+}
+
+#[test]
+// compare the results and the lazy evaluation of the rules against the macros
+fn test_random_rules_agree_with_the_macros() {
+    let mut rng = thread_rng();
+    let pool = [-2., -1., 0., 0.5, 1., 2., f64::NAN];
+
+    for _ in 1..10000 {
+        let variables: BTreeMap<&str, f64> = ["a", "b", "c", "d", "lo", "hi"]
+            .into_iter()
+            .map(|name| (name, *pool.choose(&mut rng).unwrap()))
+            .collect();
+
+        assert_rule_agrees!(variables, "any_of({a, b, c} > d)", |get| any_of!(
+            {get("a"), get("b"), get("c")} > get("d")
+        ));
+        assert_rule_agrees!(
+            variables,
+            "all_of!({a, 0.5, b} <= c) || none_of!({d, a} == 0)",
+            |get| all_of!({get("a"), 0.5, get("b")} <= get("c"))
+                || none_of!({get("d"), get("a")} == 0.)
+        );
+        assert_rule_agrees!(
+            variables,
+            "none_of({a, b}.map(double) != -2) && !any_of({c, d}.map(double).map(negate) < d)",
+            |get| none_of!({get("a"), get("b")}.map(double) != -2.)
+                && !any_of!({get("c"), get("d")}.map(double).map(negate) < get("d"))
+        );
+        assert_rule_agrees!(variables, "all_of({a, b, c} in lo..hi)", |get| all_of!(
+            {get("a"), get("b"), get("c")}.satisfy(|x| (get("lo")..get("hi")).contains(&x))
+        ));
+        assert_rule_agrees!(
+            variables,
+            "any_of({a, b} in ..=hi) || all_of({c, d}.map(double) in lo..)",
+            |get| any_of!({get("a"), get("b")}.satisfy(|x| (..=get("hi")).contains(&x)))
+                || all_of!({get("c"), get("d")}.map(double).satisfy(|x| (get("lo")..).contains(&x)))
+        );
+        assert_rule_agrees!(
+            variables,
+            "!(any_of({a} >= b) && all_of({c, d} < a)) || none_of({b, c} in -1..=lo)",
+            |get| !(any_of!({ get("a") } >= get("b")) && all_of!({get("c"), get("d")} < get("a")))
+                || none_of!({get("b"), get("c")}.satisfy(|x| (-1.0..=get("lo")).contains(&x)))
+        );
+    }
+}
+
+#[test]
+fn reports_explain_the_evaluated_comparisons() {
+    let registry = registry();
+    let mut variables = BTreeMap::new();
+    variables.insert("cpu", 95.);
+    variables.insert("mem", 40.);
+    variables.insert("disk", 3.);
+    let rule = Rule::parse(
+        "all_of({mem, cpu, disk}.map(double) < 100) || !any_of({disk} in 0..5)",
+        &registry,
+    )
+    .unwrap();
+    let comparison = |name: &str, value, satisfied| ComparisonExplanation {
+        operand: variable(name),
+        value,
+        satisfied,
+    };
+
+    let report = rule.explain(&variables, &registry).unwrap();
+    assert_eq!(
+        report,
+        Report {
+            result: false,
+            explanation: Explanation::Or(
+                Box::new(Explanation::Quantified(QuantifiedExplanation {
+                    quantifier: Quantifier::AllOf,
+                    result: false,
+                    comparisons: vec![comparison("mem", 80., true), comparison("cpu", 190., false)],
+                })),
+                Some(Box::new(Explanation::Not(Box::new(
+                    Explanation::Quantified(QuantifiedExplanation {
+                        quantifier: Quantifier::AnyOf,
+                        result: true,
+                        comparisons: vec![comparison("disk", 3., true)],
+                    })
+                )))),
+            ),
+        }
+    );
+    assert_eq!(report.explanation.result(), report.result);
+
+    // the right hand side is missing if it is not evaluated
+    variables.insert("cpu", 10.);
+    let report = rule.explain(&variables, &registry).unwrap();
+    assert!(report.result);
+    assert!(matches!(report.explanation, Explanation::Or(_, None)));
+    assert_eq!(
+        rule.explain(&|_: &str| None, &registry),
+        Err(EvalError::UnknownVariable("mem".to_string()))
+    );
+}
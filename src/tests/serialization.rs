@@ -0,0 +1,231 @@
+use crate::rules::*;
+use std::collections::BTreeMap;
+
+use rand::prelude::*;
+
+fn registry() -> Registry {
+    Registry::new().with_map("double", |x| 2. * x)
+}
+
+const RULES: [&str; 5] = [
+    "any_of({cpu, mem} > 90) && none_of({disk} < 5)",
+    "all_of!({cpu, 0.5, mem}.map(double).map(double) <= -1e3) || !any_of({disk} != mem)",
+    "none_of({cpu} in mem..=disk) && (any_of({mem} in ..2) || all_of({disk, cpu} in -1..))",
+    "!!any_of({cpu} == 1_000)",
+    "all_of({mem, disk,} in ..=cpu) || any_of({cpu} >= 3) && none_of({mem} in 0..disk)",
+];
+
+#[test]
+fn rules_and_reports_have_a_stable_json_schema() {
+    let rule = Rule::parse("none_of({disk}.map(double) in 5..=x)", &registry()).unwrap();
+    let json = serde_json::to_string(&Versioned(&rule)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"version":1,"content":{"quantified":{"quantifier":"none_of","set":[{"variable":"disk"}],"maps":["double"],"comparison":{"in_range":{"start":{"number":5.0},"end":{"variable":"x"},"inclusive":true}}}}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Versioned<Rule>>(&json).unwrap(),
+        Versioned(rule)
+    );
+
+    let rule = Rule::parse("!any_of({a} == 1) || all_of({b} < 2)", &registry()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&rule).unwrap(),
+        serde_json::json!({"or": [
+            {"not": {"quantified": {
+                "quantifier": "any_of",
+                "set": [{"variable": "a"}],
+                "maps": [],
+                "comparison": {"compare": ["==", {"number": 1.0}]}
+            }}},
+            {"quantified": {
+                "quantifier": "all_of",
+                "set": [{"variable": "b"}],
+                "maps": [],
+                "comparison": {"compare": ["<", {"number": 2.0}]}
+            }}
+        ]})
+    );
+
+    let variables = |_: &str| Some(1.);
+    let report = rule.explain(&variables, &registry()).unwrap();
+    assert_eq!(
+        serde_json::to_value(Versioned(&report)).unwrap(),
+        serde_json::json!({"version": 1, "content": {
+            "result": true,
+            "explanation": {"or": [
+                {"not": {"quantified": {
+                    "quantifier": "any_of",
+                    "result": true,
+                    "comparisons": [{"operand": {"variable": "a"}, "value": 1.0, "satisfied": true}]
+                }}},
+                {"quantified": {
+                    "quantifier": "all_of",
+                    "result": true,
+                    "comparisons": [{"operand": {"variable": "b"}, "value": 1.0, "satisfied": true}]
+                }}
+            ]}
+        }})
+    );
+}
+
+#[test]
+fn documents_of_other_schema_versions_are_rejected() {
+    let rule = Rule::parse(RULES[0], &registry()).unwrap();
+
+    let json = serde_json::to_string(&Versioned(&rule))
+        .unwrap()
+        .replace(r#""version":1"#, r#""version":2"#);
+    let error = serde_json::from_str::<Versioned<Rule>>(&json).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("unsupported schema version 2, expected version 1"));
+    // the version is checked before the content, so the error is the same if the content changed as well
+    let json = r#"{"version":2,"content":{"a_new_rule":[]}}"#;
+    let error = serde_json::from_str::<Versioned<Rule>>(json).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("unsupported schema version 2"));
+    assert!(serde_json::from_str::<Versioned<Rule>>(r#"{"content":{"not":null}}"#).is_err());
+
+    let mut bytes = postcard::to_allocvec(&Versioned(&rule)).unwrap();
+    assert_eq!(bytes[0], 1);
+    bytes[0] = 2;
+    assert!(postcard::from_bytes::<Versioned<Rule>>(&bytes).is_err());
+}
+
+#[test]
+fn invalid_rules_are_rejected_like_by_the_parser() {
+    let json = |content: &str| format!(r#"{{"version":1,"content":{{"quantified":{}}}}}"#, content);
+    let quantified = |set: &str, maps: &str, range: &str| {
+        json(&format!(
+            r#"{{"quantifier":"any_of","set":{},"maps":{},"comparison":{{"in_range":{}}}}}"#,
+            set, maps, range
+        ))
+    };
+    let valid = quantified(
+        r#"[{"variable":"cpu"}]"#,
+        r#"["double"]"#,
+        r#"{"start":null,"end":{"number":5.0},"inclusive":false}"#,
+    );
+    let rule = serde_json::from_str::<Versioned<Rule>>(&valid).unwrap().0;
+    assert_eq!(rule.validate(&registry()), Ok(()));
+    assert_eq!(
+        rule,
+        Rule::parse("any_of({cpu}.map(double) in ..5)", &registry()).unwrap()
+    );
+
+    let error = serde_json::from_str::<Versioned<Rule>>(&quantified(
+        "[]",
+        "[]",
+        r#"{"start":{"number":1.0},"end":null,"inclusive":false}"#,
+    ))
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("the set must contain at least one value"));
+    let error = serde_json::from_str::<Versioned<Rule>>(&quantified(
+        r#"[{"number":1.0}]"#,
+        "[]",
+        r#"{"start":null,"end":null,"inclusive":true}"#,
+    ))
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("the full range `..` is not allowed"));
+
+    // the registry is not available during deserialization, so unknown maps are found by validating the rule
+    let rule = serde_json::from_str::<Versioned<Rule>>(&quantified(
+        r#"[{"variable":"cpu"}]"#,
+        r#"["double","triple"]"#,
+        r#"{"start":{"number":1.0},"end":null,"inclusive":false}"#,
+    ))
+    .unwrap()
+    .0;
+    assert_eq!(
+        rule.validate(&registry()),
+        Err(ParseErrorKind::UnknownMap("triple".to_string()))
+    );
+    let mut rule = Rule::parse(RULES[0], &registry()).unwrap();
+    for _ in 0..MAX_NESTING {
+        rule = Rule::Not(Box::new(rule));
+    }
+    assert_eq!(
+        rule.validate(&registry()),
+        Err(ParseErrorKind::TooDeeplyNested)
+    );
+
+    let full_range = Range {
+        start: None,
+        end: None,
+        inclusive: false,
+    };
+    let bytes = postcard::to_allocvec(&full_range).unwrap();
+    assert!(postcard::from_bytes::<Range>(&bytes).is_err());
+}
+
+#[test]
+// serialize into a buffer on the stack and deserialize from it without allocating, like in no_std environments
+fn rules_roundtrip_through_postcard_without_allocating_the_buffer() {
+    let rule = Rule::parse(RULES[2], &registry()).unwrap();
+    let mut buffer = [0u8; 256];
+    let bytes = postcard::to_slice(&Versioned(&rule), &mut buffer).unwrap();
+    let Versioned(deserialized): Versioned<Rule> = postcard::from_bytes(bytes).unwrap();
+    assert_eq!(deserialized, rule);
+}
+
+#[test]
+// roundtrip the rules and the reports of their evaluation for random variables through both formats
+fn test_random_rules_and_reports_roundtrip() {
+    let registry = registry();
+    let rules: Vec<Rule> = RULES
+        .iter()
+        .map(|text| Rule::parse(text, &registry).unwrap())
+        .collect();
+    let mut rng = thread_rng();
+
+    for _ in 1..1000 {
+        let mut variables: BTreeMap<&str, f64> = ["cpu", "mem", "disk"]
+            .into_iter()
+            .map(|name| (name, rng.gen_range(-3..3) as f64 / 2.))
+            .collect();
+
+        for rule in &rules {
+            let report = rule.explain(&variables, &registry).unwrap();
+            assert_eq!(Ok(report.result), rule.evaluate(&variables, &registry));
+
+            let json = serde_json::to_string(&Versioned(rule)).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Versioned<Rule>>(&json).unwrap().0,
+                *rule
+            );
+            let json = serde_json::to_string(&Versioned(&report)).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Versioned<Report>>(&json).unwrap().0,
+                report
+            );
+
+            let bytes = postcard::to_allocvec(&Versioned(rule)).unwrap();
+            assert_eq!(
+                postcard::from_bytes::<Versioned<Rule>>(&bytes).unwrap().0,
+                *rule
+            );
+            let bytes = postcard::to_allocvec(&Versioned(&report)).unwrap();
+            assert_eq!(
+                postcard::from_bytes::<Versioned<Report>>(&bytes).unwrap().0,
+                report
+            );
+        }
+
+        // postcard also roundtrips NaN values, which JSON can't represent
+        variables.insert("mem", f64::NAN);
+        let report = rules[1].explain(&variables, &registry).unwrap();
+        let bytes = postcard::to_allocvec(&Versioned(&report)).unwrap();
+        let Versioned(deserialized): Versioned<Report> = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.result, report.result);
+        assert_eq!(
+            postcard::to_allocvec(&deserialized).unwrap(),
+            postcard::to_allocvec(&report).unwrap()
+        );
+    }
+}
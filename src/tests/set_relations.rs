@@ -0,0 +1,116 @@
+use crate::all_distinct;
+use crate::all_equal;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn all_equal_gives_correct_results() {
+    assert!(all_equal!({ 1 }));
+    assert!(all_equal!({1, 2 - 1, 3 - 2,}));
+    assert_eq!(all_equal!({1, 1, 2}), false);
+    assert_eq!(all_equal!({2, 1, 1}), false);
+    assert!(all_equal!({"one", String::from("one"), "one"}));
+    assert_eq!(all_equal!({f64::NAN, f64::NAN}), false);
+
+    let (a, b, c) = ("one", "two", "six");
+    assert_eq!(all_equal!({a, b, c}), false);
+    assert!(all_equal!({a, b, c}.by_key(str::len)));
+    assert!(all_equal!({a, b, c}.by_key(|s| s.len() % 3)));
+    assert_eq!(all_equal!({a, b, c}.by_key(|s| s.chars().next())), false);
+}
+
+#[test]
+fn all_distinct_gives_correct_results() {
+    assert!(all_distinct!({ 1 }));
+    assert!(all_distinct!({1, 2, 3, 4,}));
+    assert_eq!(all_distinct!({1, 2, 3, 1}), false);
+    assert_eq!(all_distinct!({1, 2, 3, 3}), false);
+    assert_eq!(all_distinct!({"one", String::from("one")}), false);
+    // NaN is not equal to itself
+    assert!(all_distinct!({f64::NAN, f64::NAN}));
+
+    let (a, b, c) = ("one", "two", "three");
+    assert!(all_distinct!({a, b, c}));
+    assert_eq!(all_distinct!({a, b, c}.by_key(str::len)), false);
+    assert!(all_distinct!({a, b, c}.by_key(|s| s.chars().nth(1))));
+}
+
+#[test]
+fn pairwise_relations_evaluate_each_value_once_and_lazily() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert_eq!(all_equal!({counted(1), counted(2), counted(2)}), false);
+    assert_eq!(count.get(), 2);
+    assert!(all_equal!({counted(1), counted(1), counted(1)}));
+    assert_eq!(count.get(), 5);
+    assert_eq!(
+        all_distinct!({counted(1), counted(2), counted(1), counted(3)}),
+        false
+    );
+    assert_eq!(count.get(), 8);
+    assert!(all_distinct!({counted(1), counted(2), counted(3)}));
+    assert_eq!(count.get(), 11);
+
+    let keys = Cell::new(0);
+    let key = |x: &i32| {
+        keys.set(keys.get() + 1);
+        x % 2
+    };
+    assert!(all_equal!({1, 3, 5}.by_key(key)));
+    assert_eq!(keys.get(), 3);
+    assert_eq!(all_distinct!({1, 2, 3, 4}.by_key(key)), false);
+    assert_eq!(keys.get(), 6);
+}
+
+#[test]
+fn all_distinct_performs_the_minimal_number_of_comparisons() {
+    #[derive(Debug)]
+    struct Counted<'a>(i32, &'a Cell<usize>);
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.1.set(self.1.get() + 1);
+            self.0 == other.0
+        }
+    }
+    let comparisons = Cell::new(0);
+    let value = |x| Counted(x, &comparisons);
+    assert!(all_distinct!({value(1), value(2), value(3), value(4)}));
+    assert_eq!(comparisons.get(), 6);
+    comparisons.set(0);
+    assert!(all_equal!({value(1), value(1), value(1), value(1)}));
+    assert_eq!(comparisons.get(), 3);
+}
+
+#[test]
+// compare the results against solutions using slices
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: i32 = rng.gen_range(-3..3);
+        let b = rng.gen_range(-3..3);
+        let c = rng.gen_range(-3..3);
+        let d = rng.gen_range(-3..3);
+        let values = [a, b, c, d];
+        let distinct =
+            |values: &[i32]| (0..values.len()).all(|i| (0..i).all(|j| values[i] != values[j]));
+
+        assert_all_eq!(
+            all_equal!({a, b, c, d}),
+            values.windows(2).all(|w| w[0] == w[1])
+        );
+        assert_all_eq!(
+            all_equal!({a, b, c, d}.by_key(|x| x.abs())),
+            values.windows(2).all(|w| w[0].abs() == w[1].abs())
+        );
+        assert_all_eq!(all_distinct!({a, b, c, d}), distinct(&values));
+        assert_all_eq!(
+            all_distinct!({a, b, c, d}.by_key(|x| x.abs())),
+            distinct(&values.map(i32::abs))
+        );
+    }
+}
@@ -0,0 +1,101 @@
+#![allow(clippy::many_single_char_names)]
+
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use crate::simd::{all_of_simd, any_of_simd, none_of_simd};
+
+use rand::prelude::*;
+
+// helper function to produce a random float, which is NaN with a probability of about 1/10
+fn random_float_or_nan(rng: &mut ThreadRng) -> f32 {
+    if rng.gen_range(0..10) == 0 {
+        f32::NAN
+    } else {
+        rng.gen_range(-5..5) as f32
+    }
+}
+
+#[test]
+fn simd_quantifiers_give_correct_results() {
+    let lanes = [1_i32, 2, 3, 4, 5, 6, 7, 8];
+    assert!(all_of_simd(&lanes).lt(9));
+    assert!(!all_of_simd(&lanes).lt(8));
+    assert!(all_of_simd(&lanes).ge(1));
+    assert!(any_of_simd(&lanes).eq(8));
+    assert!(!any_of_simd(&lanes).gt(8));
+    assert!(any_of_simd(&lanes).ne(1));
+    assert!(none_of_simd(&lanes).le(0));
+    assert!(!none_of_simd(&lanes).eq(5));
+
+    // values beyond a multiple of the number of lanes
+    let values = [1.0_f64, 2., 3., 4., 5., 6., 7., 8., 9., f64::NAN];
+    assert!(any_of_simd(&values).gt(8.5));
+    assert!(!all_of_simd(&values).lt(100.));
+    assert!(none_of_simd(&values).eq(f64::NAN));
+    assert!(all_of_simd(&values[..9]).lt(100.));
+    assert!(!any_of_simd::<u8>(&[]).eq(0));
+    assert!(all_of_simd::<u8>(&[]).eq(0));
+
+    // the macro variant
+    let (a, b, c, d) = (1.0_f32, 2., 3., 4.);
+    assert!(all_of!(simd { a, b, c, d } <= 4.));
+    assert!(any_of!(simd { a, b, c, d } == 3.));
+    assert!(none_of!(simd { a, b, c, d } > 4.));
+    assert!(any_of!(simd { a, b, c, d }.map(|x| x * x) == 16.));
+    assert!(!all_of!(simd { a, b, c, d }.map(|x| x - 1.) != 0.));
+}
+
+// compare the SIMD quantifiers and the simd macro variant with the scalar macros for every operator
+macro_rules! assert_like_scalar_macros {
+    ($values:expr, $set:tt, $rhs:expr) => {
+        assert_like_scalar_macros!(@operators $values, $set, $rhs, eq ==, ne !=, lt <, le <=, gt >, ge >=);
+    };
+    (@operators $values:expr, $set:tt, $rhs:expr, $($method:ident $operator:tt),+) => {
+        $(
+            assert_all_eq!(
+                all_of_simd(&$values).$method($rhs),
+                all_of!(simd $set $operator $rhs),
+                all_of!($set $operator $rhs)
+            );
+            assert_all_eq!(
+                any_of_simd(&$values).$method($rhs),
+                any_of!(simd $set $operator $rhs),
+                any_of!($set $operator $rhs)
+            );
+            assert_all_eq!(
+                none_of_simd(&$values).$method($rhs),
+                none_of!(simd $set $operator $rhs),
+                none_of!($set $operator $rhs)
+            );
+        )+
+    };
+}
+
+#[test]
+// compare full vectors of values and values beyond a multiple of the number of lanes against the scalar macros
+fn test_random_collection_of_values_behave_like_scalar_macros() {
+    let mut rng = thread_rng();
+
+    for _ in 1..10000 {
+        let f: [f32; 16] = std::array::from_fn(|_| random_float_or_nan(&mut rng));
+        let rhs = random_float_or_nan(&mut rng);
+        let [f0, f1, f2, f3, f4, f5, f6, f7, f8, f9, f10, f11, f12, f13, f14, f15] = f;
+        assert_like_scalar_macros!(
+            f,
+            {f0,f1,f2,f3,f4,f5,f6,f7,f8,f9,f10,f11,f12,f13,f14,f15},
+            rhs
+        );
+        assert_like_scalar_macros!(f[..11], {f0,f1,f2,f3,f4,f5,f6,f7,f8,f9,f10}, rhs);
+
+        let i: [i32; 8] = std::array::from_fn(|_| rng.gen_range(-5..5));
+        let rhs = rng.gen_range(-5..5);
+        let [i0, i1, i2, i3, i4, i5, i6, i7] = i;
+        assert_like_scalar_macros!(i, {i0,i1,i2,i3,i4,i5,i6,i7}, rhs);
+        assert_like_scalar_macros!(
+            [i0, i1, i2, i3, i4, i5, i6, i7, i0, i1, i2],
+            {i0,i1,i2,i3,i4,i5,i6,i7,i0,i1,i2},
+            rhs
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::is_sorted;
+use crate::is_strictly_decreasing;
+use crate::is_strictly_increasing;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn is_sorted_gives_correct_results() {
+    assert!(is_sorted!({ 1 }));
+    assert!(is_sorted!({1, 2, 2, 3,}));
+    assert_eq!(is_sorted!({1, 3, 2}), false);
+    assert_eq!(is_sorted!({2, 1, 3}), false);
+    assert!(is_sorted!({"a", "b", "b"}));
+    // NaN is not ordered with respect to any value
+    assert_eq!(is_sorted!({1., f64::NAN, 2.}), false);
+
+    let (a, b, c) = ("tree", "Apple", "banana");
+    assert_eq!(is_sorted!({a, b, c}), false);
+    assert!(is_sorted!({a, b, c}.by_key(str::len)));
+    assert!(is_sorted!({b, c, a}.by_key(|s| s.to_lowercase())));
+}
+
+#[test]
+fn is_strictly_increasing_and_decreasing_give_correct_results() {
+    assert!(is_strictly_increasing!({ 1 }));
+    assert!(is_strictly_increasing!({1, 2, 3,}));
+    assert_eq!(is_strictly_increasing!({1, 2, 2}), false);
+    assert_eq!(is_strictly_increasing!({2, 1, 3}), false);
+    assert!(is_strictly_decreasing!({ 1 }));
+    assert!(is_strictly_decreasing!({3, 2, 1,}));
+    assert_eq!(is_strictly_decreasing!({3, 2, 2}), false);
+    assert_eq!(is_strictly_decreasing!({1., f64::NAN}), false);
+    assert_eq!(is_strictly_increasing!({1., f64::NAN}), false);
+
+    let (a, b, c) = ("a", "bb", "ccc");
+    assert!(is_strictly_increasing!({a, b, c}.by_key(str::len)));
+    assert!(is_strictly_decreasing!({c, b, a}.by_key(|s| s.len())));
+    assert_eq!(is_strictly_increasing!({a, b, b}.by_key(str::len)), false);
+}
+
+#[test]
+fn sortedness_checks_evaluate_each_value_once_and_lazily() {
+    let count = Cell::new(0);
+    let counted = |x: i32| {
+        count.set(count.get() + 1);
+        x
+    };
+    assert_eq!(is_sorted!({counted(1), counted(0), counted(2)}), false);
+    assert_eq!(count.get(), 2);
+    assert!(is_sorted!({counted(1), counted(1), counted(2)}));
+    assert_eq!(count.get(), 5);
+    assert_eq!(
+        is_strictly_increasing!({counted(1), counted(2), counted(2), counted(3)}),
+        false
+    );
+    assert_eq!(count.get(), 8);
+    assert!(is_strictly_decreasing!({counted(3), counted(2), counted(1)}));
+    assert_eq!(count.get(), 11);
+
+    let keys = Cell::new(0);
+    let key = |x: &i32| {
+        keys.set(keys.get() + 1);
+        x.abs()
+    };
+    assert!(is_sorted!({1, -2, 3}.by_key(key)));
+    assert_eq!(keys.get(), 3);
+    assert_eq!(is_strictly_increasing!({1, -1, 2, 3}.by_key(key)), false);
+    assert_eq!(keys.get(), 5);
+}
+
+#[test]
+fn sortedness_checks_perform_one_comparison_per_adjacent_pair() {
+    #[derive(Debug)]
+    struct Counted<'a>(i32, &'a Cell<usize>);
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            self.1.set(self.1.get() + 1);
+            self.0.partial_cmp(&other.0)
+        }
+    }
+    let comparisons = Cell::new(0);
+    let value = |x| Counted(x, &comparisons);
+    assert!(is_sorted!({value(1), value(2), value(2), value(4)}));
+    assert_eq!(comparisons.get(), 3);
+    comparisons.set(0);
+    assert!(is_strictly_decreasing!({value(4), value(3), value(2), value(1)}));
+    assert_eq!(comparisons.get(), 3);
+}
+
+#[test]
+// compare the results against solutions using slices
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: i32 = rng.gen_range(-3..3);
+        let b = rng.gen_range(-3..3);
+        let c = rng.gen_range(-3..3);
+        let d = rng.gen_range(-3..3);
+        let values = [a, b, c, d];
+
+        assert_all_eq!(
+            is_sorted!({a, b, c, d}),
+            values.windows(2).all(|w| w[0] <= w[1])
+        );
+        assert_all_eq!(
+            is_sorted!({a, b, c, d}.by_key(|x| x.abs())),
+            values.windows(2).all(|w| w[0].abs() <= w[1].abs())
+        );
+        assert_all_eq!(
+            is_strictly_increasing!({a, b, c, d}),
+            values.windows(2).all(|w| w[0] < w[1])
+        );
+        assert_all_eq!(
+            is_strictly_decreasing!({a, b, c, d}),
+            values.windows(2).all(|w| w[0] > w[1])
+        );
+        assert_all_eq!(
+            is_strictly_decreasing!({a, b, c, d}.by_key(|x| x.abs())),
+            values.windows(2).all(|w| w[0].abs() > w[1].abs())
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+
+use rand::prelude::*;
+
+#[test]
+fn spread_values_give_correct_results() {
+    let values = vec![1, 5, 9];
+    assert!(any_of!({ ..&values } > &8));
+    assert_eq!(any_of!({ ..&values } > &9), false);
+    assert!(all_of!({ ..values.iter().copied() } < 10));
+    assert!(none_of!({ ..[1, 5, 9] } == 4));
+    assert!(all_of!({..1..10} >= 1));
+    // spreading an empty set is vacuously true for all_of and none_of
+    assert!(all_of!({ ..Vec::<i32>::new() } > 0));
+    assert!(none_of!({ ..Vec::<i32>::new() } > 0));
+    assert_eq!(any_of!({ ..Vec::<i32>::new() } > 0), false);
+
+    // with modifiers and chains
+    assert!(all_of!({ ..[1, 2, 7] }.map(|x| x * x) < 50));
+    assert!(any_of!(
+        { ..values.iter().copied() }
+            .filter(|x| *x > 1)
+            .map(|x| x * 2)
+            == 18
+    ));
+    assert!(any_of!(
+        { ..["Guest", "ADMIN"] }.by_key(str::to_lowercase) == "admin"
+    ));
+    assert!(all_of!({ ..&values }.satisfy(|x: &i32| x % 2 == 1)));
+}
+
+#[test]
+fn spread_values_are_lazy_and_evaluate_the_rhs_once() {
+    let count = Cell::new(0);
+    let counted = |x: &i32| {
+        count.set(count.get() + 1);
+        *x
+    };
+    let rhs_count = Cell::new(0);
+    let rhs = || {
+        rhs_count.set(rhs_count.get() + 1);
+        5
+    };
+    let values = [1, 7, 3, 9];
+    assert_eq!(all_of!({ ..&values }.map(counted) < rhs()), false);
+    assert_eq!((count.get(), rhs_count.get()), (2, 1));
+    assert!(any_of!({ ..&values }.map(counted) > rhs()));
+    assert_eq!((count.get(), rhs_count.get()), (4, 2));
+}
+
+#[test]
+fn functions_of_the_modifiers_are_evaluated_once() {
+    let count = Cell::new(0);
+    let make_abs = || {
+        count.set(count.get() + 1);
+        |x: i32| x.abs()
+    };
+    let make_filter = || {
+        count.set(count.get() + 1);
+        |x: &i32| *x > 1
+    };
+    let values = [-4, 1, 7, -3];
+    assert!(all_of!(
+        { ..values }.map(make_abs()).filter(make_filter()) > 2
+    ));
+    assert_eq!(count.get(), 2);
+    assert!(none_of!({ ..values }.map(make_abs()) > 7));
+    assert_eq!(count.get(), 3);
+
+    let make_key = || {
+        count.set(count.get() + 1);
+        |x: &i32| x.abs()
+    };
+    let all_small = all_of!({_: [i32; 4]}.by_key(make_key()) < 8);
+    assert_eq!(count.get(), 3);
+    assert!(all_small(values));
+    assert_eq!(count.get(), 4);
+}
+
+#[test]
+// compare the results against the macros with the values written out
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a: i32 = rng.gen_range(-5..5);
+        let b = rng.gen_range(-5..5);
+        let c = rng.gen_range(-5..5);
+        let rhs = rng.gen_range(-5..5);
+        let values = vec![a, b, c];
+
+        assert_all_eq!(any_of!({ ..values.clone() } < rhs), any_of!({a,b,c} < rhs));
+        assert_all_eq!(all_of!({ ..&values } >= &rhs), all_of!({a,b,c} >= rhs));
+        assert_all_eq!(
+            none_of!({ ..values.iter().copied() }.map(|x| x * x) == rhs),
+            none_of!({a,b,c}.map(|x| x * x) == rhs)
+        );
+    }
+}
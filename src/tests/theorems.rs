@@ -68,3 +68,64 @@ fn test_random_collection_of_values_behave_correctly() {
         "none_of and any_of give inconsistent results"
     );
 }
+
+#[test]
+// the same theorems for floating point numbers, where some of the values are NaN. They only hold
+// when comparing with respect to the total order or when all comparisons are ordered
+fn test_random_collection_of_floats_including_nan_behave_correctly() {
+    let mut rng = thread_rng();
+    const EXPERIMENT_COUNT: usize = 1000000;
+
+    let mut random_float = || {
+        if rng.gen_range(0..4) == 0 {
+            f64::NAN
+        } else {
+            rng.gen_range(-5..5) as f64
+        }
+    };
+
+    let mut plain_theorem_violated = false;
+    for _ in 1..EXPERIMENT_COUNT {
+        let a = random_float();
+        let b = random_float();
+        let c = random_float();
+        let rhs = random_float();
+
+        // 1) all_of!({...}<rhs) == none_of!({...}>=rhs)
+        let all_of_le = all_of!({a,b,c}.total()<rhs);
+        let none_of_geq = none_of!({a,b,c}.total()>=rhs);
+        let any_of_le = any_of!({a,b,c}.total()<rhs);
+        assert_eq!(
+            all_of_le, none_of_geq,
+            "all_of and none_of give inconsistent results"
+        );
+        // 2) all_of!({...}<rhs) == true implies that any_of!({...}< rhs) == true
+        assert!(
+            !all_of_le || any_of_le,
+            "all_of and any_of give inconsistent results"
+        );
+        // 3) none_of!({...}>= rhs) == true implies that any_of!({...}<rhs) == true
+        assert!(
+            !none_of_geq || any_of_le,
+            "none_of and any_of give inconsistent results"
+        );
+
+        // the strict comparisons only give a result if it is consistent
+        if let (Some(all_of_le), Some(none_of_geq)) = (
+            all_of!({a,b,c}.strict()<rhs),
+            none_of!({a,b,c}.strict()>=rhs),
+        ) {
+            assert_eq!(
+                all_of_le, none_of_geq,
+                "strict all_of and none_of give inconsistent results"
+            );
+        }
+
+        plain_theorem_violated |= all_of!({a,b,c}<rhs) != none_of!({a,b,c}>=rhs);
+    }
+    // a sanity check that NaN actually made a difference for the partial order
+    assert!(
+        plain_theorem_violated,
+        "NaN values must violate the theorem for the partial order"
+    );
+}
@@ -0,0 +1,132 @@
+#![allow(clippy::many_single_char_names)]
+
+use crate::all_of;
+use crate::any_of;
+use crate::none_of;
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use rand::prelude::*;
+
+// helper function to produce a random float, which is NaN with a probability of about 1/5
+fn random_float_or_nan(rng: &mut ThreadRng) -> f64 {
+    if rng.gen_range(0..5) == 0 {
+        f64::NAN
+    } else {
+        rng.gen_range(-5..5) as f64
+    }
+}
+
+#[test]
+fn total_comparisons_give_correct_result_for_nan() {
+    let nan = f64::NAN;
+    // NaN sorts above all numbers, but is equal to itself
+    assert!(any_of!({1.,nan}.total()>f64::INFINITY));
+    assert!(all_of!({nan,nan}.total()==nan));
+    assert!(none_of!({1.,nan}.total()<0.));
+    assert!(all_of!({-nan,-1.}.total()<0.));
+    assert_eq!(all_of!({1.,nan}.total()<=2.), false);
+    assert_eq!(none_of!({1.,nan}.total()>2.), false);
+    assert_eq!(any_of!({1.,nan}.total()!=nan), true);
+
+    // -0 and +0 are distinct in the total order
+    assert!(all_of!({ -0.0_f64 } <= 0.));
+    assert!(all_of!({ -0.0_f64 }.total() < 0.));
+
+    // also with map. The sign of the NaN produced by sqrt is not specified, so we use our own
+    let sqrt_or_nan = |x: f64| if x < 0. { nan } else { x.sqrt() };
+    assert!(any_of!({1.,4.}.map(sqrt_or_nan).total()==2.));
+    assert!(any_of!({1.,-4.}.map(sqrt_or_nan).total()>2.));
+    assert!(none_of!({1.,-4.}.map(sqrt_or_nan).total()<0.));
+    assert_eq!(all_of!({1.,-4.}.map(sqrt_or_nan).total()<=2.), false);
+
+    // other types than floats
+    assert!(all_of!({1,2,3}.total()<4));
+    assert!(any_of!({'a','b'}.total()=='b'));
+}
+
+#[test]
+fn strict_comparisons_give_correct_result_for_nan() {
+    let nan = f64::NAN;
+    assert_eq!(any_of!({1.,2.}.strict()>1.5), Some(true));
+    assert_eq!(any_of!({1.,2.}.strict()>2.5), Some(false));
+    assert_eq!(any_of!({1.,nan}.strict()>1.5), None);
+    assert_eq!(all_of!({1.,2.}.strict()<3.), Some(true));
+    assert_eq!(all_of!({1.,2.}.strict()<1.5), Some(false));
+    assert_eq!(all_of!({1.,nan}.strict()<3.), None);
+    assert_eq!(none_of!({1.,2.}.strict()>3.), Some(true));
+    assert_eq!(none_of!({1.,2.}.strict()>1.5), Some(false));
+    assert_eq!(none_of!({1.,nan}.strict()>3.), None);
+    assert_eq!(all_of!({1.,2.}.strict()!=nan), None);
+    assert_eq!(any_of!({1.,2.}.strict()==nan), None);
+
+    // also with map
+    assert_eq!(any_of!({1.,-4.}.map(f64::sqrt).strict()>0.), Some(true));
+    assert_eq!(all_of!({1.,-4.}.map(f64::sqrt).strict()>0.), None);
+    assert_eq!(none_of!({1.,4.}.map(f64::sqrt).strict()>2.), Some(true));
+}
+
+#[test]
+fn strict_comparisons_evaluate_lazily() {
+    let nan = f64::NAN;
+    let count = Cell::new(0);
+    let counted = |x: f64| {
+        count.set(count.get() + 1);
+        x
+    };
+    // the result is decided before the NaN is compared
+    assert_eq!(any_of!({counted(2.),counted(nan)}.strict()>1.), Some(true));
+    assert_eq!(count.get(), 1);
+    assert_eq!(all_of!({counted(0.),counted(nan)}.strict()>1.), Some(false));
+    assert_eq!(count.get(), 2);
+    assert_eq!(
+        none_of!({counted(2.),counted(nan)}.strict()>1.),
+        Some(false)
+    );
+    assert_eq!(count.get(), 3);
+    // the evaluation stops at the first unordered comparison
+    assert_eq!(any_of!({counted(nan),counted(2.)}.strict()>1.), None);
+    assert_eq!(count.get(), 4);
+    assert_eq!(all_of!({2.,3.}.map(counted).strict()>1.), Some(true));
+    assert_eq!(count.get(), 6);
+}
+
+#[test]
+// compare the results against a solution using standard library iterators
+fn test_random_collection_of_floats_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = random_float_or_nan(&mut rng);
+        let b = random_float_or_nan(&mut rng);
+        let c = random_float_or_nan(&mut rng);
+        let rhs = random_float_or_nan(&mut rng);
+
+        assert_all_eq!(
+            any_of!({a,b,c}.total()<rhs),
+            [a, b, c]
+                .iter()
+                .any(|v| v.total_cmp(&rhs) == Ordering::Less)
+        );
+        assert_all_eq!(
+            all_of!({a,b,c}.total()>=rhs),
+            [a, b, c]
+                .iter()
+                .all(|v| v.total_cmp(&rhs) != Ordering::Less)
+        );
+        assert_all_eq!(
+            none_of!({a,b,c}.map(|x|x+1.).total()==rhs),
+            ![a, b, c]
+                .iter()
+                .any(|v| (v + 1.).total_cmp(&rhs) == Ordering::Equal)
+        );
+
+        let strict_all_of = all_of!({a,b,c}.strict()<=rhs);
+        let all_ordered = [a, b, c].iter().all(|v| v.partial_cmp(&rhs).is_some());
+        if all_ordered {
+            assert_all_eq!(strict_all_of, Some(all_of!({a,b,c}<=rhs)));
+        } else {
+            assert!(strict_all_of.is_none() || strict_all_of == Some(false));
+        }
+    }
+}
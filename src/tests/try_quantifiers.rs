@@ -0,0 +1,131 @@
+use crate::try_all_of;
+use crate::try_any_of;
+use crate::try_none_of;
+use std::cell::Cell;
+use std::num::ParseIntError;
+
+use rand::prelude::*;
+
+// helper function that fails for negative values
+fn checked_sqrt(x: i32) -> Result<i32, String> {
+    if x < 0 {
+        Err(format!("{} is negative", x))
+    } else {
+        Ok((x as f64).sqrt() as i32)
+    }
+}
+
+#[test]
+fn try_quantifiers_give_correct_result_for_results() {
+    assert_eq!(try_any_of!({4,16}.map(checked_sqrt) == 4), Ok(true));
+    assert_eq!(try_any_of!({4,9}.map(checked_sqrt) == 4), Ok(false));
+    assert_eq!(
+        try_any_of!({4,-9,16}.map(checked_sqrt) == 4),
+        Err("-9 is negative".to_string())
+    );
+
+    assert_eq!(try_all_of!({4,16}.map(checked_sqrt) >= 2), Ok(true));
+    assert_eq!(try_all_of!({4,1}.map(checked_sqrt) >= 2), Ok(false));
+    assert_eq!(
+        try_all_of!({4,-1}.map(checked_sqrt) >= 2),
+        Err("-1 is negative".to_string())
+    );
+
+    assert_eq!(try_none_of!({4,16}.map(checked_sqrt) > 4), Ok(true));
+    assert_eq!(try_none_of!({4,25}.map(checked_sqrt) > 4), Ok(false));
+    assert_eq!(
+        try_none_of!({-4,25}.map(checked_sqrt) > 4),
+        Err("-4 is negative".to_string())
+    );
+
+    // with satisfy
+    let is_perfect_square = |x: i32| checked_sqrt(x).map(|root| root * root == x);
+    assert_eq!(try_any_of!({2,4}.satisfy(is_perfect_square)), Ok(true));
+    assert_eq!(try_all_of!({2,4}.satisfy(is_perfect_square)), Ok(false));
+    assert!(try_none_of!({2,-4}.satisfy(is_perfect_square)).is_err());
+
+    // without map the values themselves are results
+    let parse = |s: &str| s.parse::<i32>();
+    assert_eq!(try_any_of!({parse("1"),parse("2")} == 2), Ok(true));
+    assert!(try_all_of!({parse("1"),parse("zwei")} > 0).is_err());
+}
+
+#[test]
+fn try_quantifiers_give_correct_result_for_options() {
+    let values = [1, 2, 3];
+    let lookup = |index: usize| values.get(index).copied();
+    assert_eq!(try_any_of!({0,1}.map(lookup) == 2), Some(true));
+    assert_eq!(try_all_of!({0,1}.map(lookup) == 2), Some(false));
+    assert_eq!(try_none_of!({0,1,2}.map(lookup) > 3), Some(true));
+    assert_eq!(try_none_of!({0,5,2}.map(lookup) > 3), None);
+    assert_eq!(
+        try_all_of!({0,2}.satisfy(|i|lookup(i).map(|v|v%2==1))),
+        Some(true)
+    );
+}
+
+#[test]
+fn try_quantifiers_propagate_errors_with_question_mark() {
+    fn all_positive(inputs: [&str; 3]) -> Result<bool, ParseIntError> {
+        let [a, b, c] = inputs;
+        let positive = try_all_of!({a,b,c}.map(str::parse::<i32>) > 0)?;
+        Ok(positive)
+    }
+    assert_eq!(all_positive(["1", "2", "3"]), Ok(true));
+    assert_eq!(all_positive(["1", "-2", "3"]), Ok(false));
+    assert!(all_positive(["1", "zwei", "3"]).is_err());
+}
+
+#[test]
+fn try_quantifiers_evaluate_lazily() {
+    let count = Cell::new(0);
+    let counted_sqrt = |x: i32| {
+        count.set(count.get() + 1);
+        checked_sqrt(x)
+    };
+    // stops at the first error
+    assert!(try_any_of!({-1,4,9}.map(counted_sqrt) == 2).is_err());
+    assert_eq!(count.get(), 1);
+    // stops when the result is known, so the error is never encountered
+    assert_eq!(try_any_of!({4,-1}.map(counted_sqrt) == 2), Ok(true));
+    assert_eq!(count.get(), 2);
+    assert_eq!(try_all_of!({1,-1}.map(counted_sqrt) == 2), Ok(false));
+    assert_eq!(count.get(), 3);
+    assert_eq!(try_none_of!({4,9,16}.map(counted_sqrt) == 2), Ok(false));
+    assert_eq!(count.get(), 4);
+}
+
+#[test]
+// compare the results against a solution using standard library iterators
+fn test_random_collection_of_values_behave_correctly() {
+    let mut rng = thread_rng();
+
+    for _ in 1..100000 {
+        let a = rng.gen_range(-2..10);
+        let b = rng.gen_range(-2..10);
+        let c = rng.gen_range(-2..10);
+        let rhs = rng.gen_range(0..4);
+
+        let roots: Result<Vec<i32>, String> = [a, b, c].iter().map(|x| checked_sqrt(*x)).collect();
+        match roots {
+            Ok(roots) => {
+                assert_all_eq!(
+                    try_any_of!({a,b,c}.map(checked_sqrt) < rhs),
+                    Ok(roots.iter().any(|v| *v < rhs))
+                );
+                assert_all_eq!(
+                    try_all_of!({a,b,c}.map(checked_sqrt) < rhs),
+                    Ok(roots.iter().all(|v| *v < rhs))
+                );
+                assert_all_eq!(
+                    try_none_of!({a,b,c}.map(checked_sqrt) < rhs),
+                    Ok(!roots.iter().any(|v| *v < rhs))
+                );
+            }
+            Err(_) => {
+                // an error may only be returned if there is one
+                assert!(try_all_of!({a,b,c}.map(checked_sqrt) < rhs) != Ok(true));
+            }
+        }
+    }
+}